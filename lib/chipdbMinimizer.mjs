@@ -6,7 +6,11 @@ import path from 'path';
 export default function chipdbMinimizer(options = {}) {
   const chipdbBaseDir = options.baseDir || 'static/chipdb';
   const outputSuffix = options.outputSuffix || '-min.bin';
-  const archList = ['ecp5', 'ice40'];
+  const archList = ['ecp5', 'ice40', 'machxo2', 'nexus'];
+  // A Nexus chipdb holds the whole family, each device is minimized into its own file
+  const nexusDevices = options.nexusDevices || ['LIFCL-17', 'LIFCL-40'];
+  // Chipdbs that only refer to nextpnr's `constids.inc` by index, which is kept next to them
+  const constidsArchs = ['machxo2', 'nexus'];
 
   return {
     name: 'chipdb-minimizer',
@@ -14,23 +18,36 @@ export default function chipdbMinimizer(options = {}) {
     async buildStart() {
       for (const arch of archList) {
         const archDir = path.join(chipdbBaseDir, arch);
-        const entries = await fs.readdir(archDir);
+        // Families without chipdbs in the tree are not built
+        const entries = await fs.readdir(archDir).catch(() => []);
 
         for (const entry of entries) {
           if (!entry.endsWith('.bin') || entry.endsWith(outputSuffix)) continue;
 
           const inputPath = path.join(archDir, entry);
-          const outputPath = path.join(archDir, entry.replace(/\.bin$/, outputSuffix));
+          const jobs = arch === 'nexus'
+            ? nexusDevices.map((device) => ({
+                extraArgs: ['--device', device],
+                outputPath: path.join(archDir, `${device}${outputSuffix}`),
+              }))
+            : [{ extraArgs: [], outputPath: path.join(archDir, entry.replace(/\.bin$/, outputSuffix)) }];
 
-          console.log(`Minimizing ${inputPath} -> ${outputPath}`);
-          const result = spawnSync(
-            options.cliPath || './target/debug/nextpnr-renderer',
-            ['--arch', arch, '--input', inputPath, '--output', outputPath],
-            { stdio: 'inherit' }
-          );
+          if (constidsArchs.includes(arch)) {
+            const constids = path.join(archDir, 'constids.inc');
+            jobs.forEach((job) => job.extraArgs.push('--constids', constids));
+          }
+
+          for (const { extraArgs, outputPath } of jobs) {
+            console.log(`Minimizing ${inputPath} -> ${outputPath}`);
+            const result = spawnSync(
+              options.cliPath || './target/debug/nextpnr-renderer',
+              ['--arch', arch, '--input', inputPath, '--output', outputPath, ...extraArgs],
+              { stdio: 'inherit' }
+            );
 
-          if (result.status !== 0) {
-            this.error(`chipdb_minimizer failed on ${inputPath}`);
+            if (result.status !== 0) {
+              this.error(`chipdb_minimizer failed on ${inputPath}`);
+            }
           }
         }
       }
@@ -1,159 +1,133 @@
-use super::types::{Architecture, Wire, WireLocation};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use super::trellis::{Trellis, TrellisPip};
+use super::types::{
+    parse_pip_name, port_direction, Architecture, BelPin, BelSite, ClockNetwork, ClockQuadrant,
+    ClockSpine, ClockTap, ClockWire, PackagePin, ResourceDelay, RoutingRegion, Wire, WireLocation,
+};
 use crate::chipdb;
 use crate::decal;
-use crate::decal::ECP5DecalID;
 use crate::gfx;
-use crate::gfx::ecp5::consts;
+use crate::gfx::ecp5::{ConstId, GfxTileWireId};
+use crate::utils::log;
 
 type DecalID = decal::ECP5DecalID;
 type Decal = decal::DecalXY<DecalID>;
 
+fn const_name(id: i32) -> String {
+    format!("{:?}", gfx::ecp5::ConstId::from(id as u32))
+}
+
 pub struct ECP5Arch {
     chipdb: chipdb::ecp5::MinimizedChipInfoPOD,
 }
 
 impl ECP5Arch {
     pub fn new(chipdb: chipdb::ecp5::MinimizedChipInfoPOD) -> Self {
+        let unknown: Vec<u32> = chipdb
+            .locations
+            .iter()
+            .flat_map(|l| &l.bel_data)
+            .filter_map(|b| match b.r#type {
+                gfx::ecp5::ConstId::Unknown(id) => Some(id),
+                _ => None,
+            })
+            .sorted()
+            .dedup()
+            .collect();
+        if !unknown.is_empty() {
+            log(format!(
+                "ECP5 chipdb has BEL types unknown to the viewer, drawn as placeholders: {unknown:?}"
+            ));
+        }
+
         ECP5Arch { chipdb }
     }
+
+    fn location(&self, x: i32, y: i32) -> Option<&chipdb::ecp5::MinimizedLocationTypePOD> {
+        if x < 0 || y < 0 || x >= self.chipdb.width || y >= self.chipdb.height {
+            return None;
+        }
+        self.chipdb
+            .location_type
+            .get((y * self.chipdb.width + x) as usize)
+            .and_then(|&t| self.chipdb.locations.get(t as usize))
+    }
+}
+
+impl Trellis for ECP5Arch {
+    const SPEED_GRADE_NAMES: &'static [&'static str] = &["6", "7", "8"];
+
+    fn width(&self) -> i32 {
+        self.chipdb.width
+    }
+
+    fn height(&self) -> i32 {
+        self.chipdb.height
+    }
+
+    fn tile_len(&self, x: i32, y: i32) -> [usize; 3] {
+        self.location(x, y).map_or([0; 3], |l| {
+            [l.bel_data.len(), l.wire_data.len(), l.pip_data.len()]
+        })
+    }
+
+    fn bel_name(&self, x: i32, y: i32, index: usize) -> Option<&str> {
+        Some(&self.location(x, y)?.bel_data.get(index)?.name)
+    }
+
+    fn bel_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, i32)> {
+        let bel = self.location(x, y)?.bel_data.get(index)?;
+        Some((bel.r#type, bel.z))
+    }
+
+    fn wire_name(&self, x: i32, y: i32, index: usize) -> Option<&str> {
+        Some(&self.location(x, y)?.wire_data.get(index)?.name)
+    }
+
+    fn wire_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, GfxTileWireId)> {
+        let wire = self.location(x, y)?.wire_data.get(index)?;
+        Some((wire.r#type, wire.tile_wire))
+    }
+
+    fn wire_pip_locators(
+        &self,
+        x: i32,
+        y: i32,
+        index: usize,
+        uphill: bool,
+    ) -> Option<&[chipdb::ecp5::MinimizedPipLocatorPOD]> {
+        let wire = self.location(x, y)?.wire_data.get(index)?;
+        Some(if uphill {
+            &wire.pips_uphill
+        } else {
+            &wire.pips_downhill
+        })
+    }
+
+    fn pip(&self, x: i32, y: i32, index: usize) -> Option<TrellisPip> {
+        let pip = self.location(x, y)?.pip_data.get(index)?;
+        let (src, dst) = (&pip.rel_src_loc, &pip.rel_dst_loc);
+        Some(TrellisPip {
+            src: (src.x as i32, src.y as i32, pip.src_idx as usize),
+            dst: (dst.x as i32, dst.y as i32, pip.dst_idx as usize),
+            timing_class: pip.timing_class as usize,
+        })
+    }
+
+    fn speed_grades(&self) -> &[chipdb::ecp5::MinimizedSpeedGradePOD] {
+        &self.chipdb.speed_grades
+    }
+
+    fn id_name(&self, id: i32) -> String {
+        const_name(id)
+    }
 }
 
 impl Architecture<DecalID> for ECP5Arch {
     fn get_decal_graphics(&self, decal: &DecalID) -> Vec<gfx::GraphicElement> {
-        if decal.r#type == decal::ECP5DecalType::TYPE_BEL {
-            let tile = decal.location.y * (self.chipdb.width as f64) + decal.location.x;
-            let Some(loc_info) = self
-                .chipdb
-                .location_type
-                .get(tile as usize)
-                .and_then(|&t| self.chipdb.locations.get(t as usize))
-            else {
-                return vec![];
-            };
-
-            let Some(bel_info) = loc_info.bel_data.get(decal.z as usize) else {
-                return vec![];
-            };
-
-            let x = decal.location.x;
-            let y = decal.location.y;
-            let z = bel_info.z;
-            let width = self.chipdb.width;
-            let height = self.chipdb.height;
-            let r#type = &bel_info.r#type;
-            let style = &gfx::Style::Inactive;
-
-            return gfx::ecp5::tile_bel(x, y, z, width, height, r#type, style);
-        } else if decal.r#type == decal::ECP5DecalType::TYPE_WIRE {
-            let tile = decal.location.y * (self.chipdb.width as f64) + decal.location.x;
-            let Some(loc_info) = self
-                .chipdb
-                .location_type
-                .get(tile as usize)
-                .and_then(|&t| self.chipdb.locations.get(t as usize))
-            else {
-                return vec![];
-            };
-
-            let Some(wire_data) = loc_info.wire_data.get(decal.z as usize) else {
-                return vec![];
-            };
-
-            let x = decal.location.x;
-            let y = decal.location.y;
-            let width = self.chipdb.width;
-            let height = self.chipdb.height;
-            let wiretype = &wire_data.r#type;
-            let tilewire = &wire_data.tile_wire;
-            let style = &gfx::Style::Inactive;
-            return gfx::ecp5::tile_wire(x, y, width, height, wiretype, tilewire, style);
-        } else if decal.r#type == decal::ECP5DecalType::TYPE_GROUP {
-            let r#type = decal.z;
-            let x = decal.location.x;
-            let y = decal.location.y;
-
-            if (r#type as i32) == 1
-            /* GroupId::TYPE_SWITCHBOX */
-            {
-                let mut el = gfx::GraphicElement::new(gfx::Type::Box, gfx::Style::Frame);
-                el.x1 = x + consts::switchbox_x1;
-                el.x2 = x + consts::switchbox_x2;
-                el.y1 = y + consts::switchbox_y1;
-                el.y2 = y + consts::switchbox_y2;
-                return vec![el];
-            }
-        } else if decal.r#type == decal::ECP5DecalType::TYPE_PIP {
-            // Utility functions to get wire/decal loc info
-            let loc_info_wire = |wire: &gfx::ecp5::WireId| {
-                let tile = wire.location.y * (self.chipdb.width as f64) + wire.location.x;
-                self.chipdb
-                    .location_type
-                    .get(tile as usize)
-                    .and_then(|&t| self.chipdb.locations.get(t as usize))
-            };
-            let loc_info_decal = |decal: &decal::ECP5DecalID| {
-                let tile = decal.location.y * (self.chipdb.width as f64) + decal.location.x;
-                self.chipdb
-                    .location_type
-                    .get(tile as usize)
-                    .and_then(|&t| self.chipdb.locations.get(t as usize))
-            };
-
-            // Get pip
-            let Some(pip) = loc_info_decal(decal).and_then(|l| l.pip_data.get(decal.z as usize))
-            else {
-                return vec![];
-            };
-
-            // Get src wire info
-            let src_wire = gfx::ecp5::WireId {
-                location: gfx::ecp5::Location {
-                    x: decal.location.x + (pip.rel_src_loc.x as f64),
-                    y: decal.location.y + (pip.rel_src_loc.y as f64),
-                },
-            };
-            let Some(loc_src_wire) = loc_info_wire(&src_wire) else {
-                return vec![];
-            };
-            let Some(src_wire_info) = loc_src_wire.wire_data.get(pip.src_idx as usize) else {
-                return vec![];
-            };
-
-            // Get dst wire info
-            let dst_wire = gfx::ecp5::WireId {
-                location: gfx::ecp5::Location {
-                    x: decal.location.x + (pip.rel_dst_loc.x as f64),
-                    y: decal.location.y + (pip.rel_dst_loc.y as f64),
-                },
-            };
-            let Some(loc_dst_wire) = loc_info_wire(&dst_wire) else {
-                return vec![];
-            };
-            let Some(dst_wire_info) = loc_dst_wire.wire_data.get(pip.dst_idx as usize) else {
-                return vec![];
-            };
-
-            let x = decal.location.x;
-            let y = decal.location.y;
-
-            let width = self.chipdb.width;
-            let height = self.chipdb.height;
-
-            let src_type = &src_wire_info.r#type;
-            let dst_type = &dst_wire_info.r#type;
-
-            let src_id = src_wire_info.tile_wire;
-            let dst_id = dst_wire_info.tile_wire;
-
-            let style = &gfx::Style::Hidden;
-
-            return gfx::ecp5::tile_pip(
-                x, y, width, height, &src_wire, src_type, &src_id, &dst_wire, dst_type, &dst_id,
-                style,
-            );
-        }
-
-        vec![]
+        self.decal_graphics(decal)
     }
 
     fn get_bel_decals(&self) -> Vec<Decal> {
@@ -258,66 +232,322 @@ impl Architecture<DecalID> for ECP5Arch {
         ret
     }
 
-    fn get_pip_decals(&self) -> Vec<Decal> {
-        let mut cursor_tile = 0;
-        let mut cursor_index = 0;
+    fn get_pip_decals(&self) -> Box<dyn Iterator<Item = Decal> + '_> {
+        let (width, height) = (self.chipdb.width, self.chipdb.height);
+        Box::new((0..width * height).flat_map(move |tile| {
+            let (x, y) = (tile % width, tile / width);
+            let len = self.location(x, y).map_or(0, |l| l.pip_data.len());
+            (0..len).filter_map(move |index| self.pip_decal(x, y, index))
+        }))
+    }
 
-        let mut ret: Vec<Decal> = vec![];
+    fn get_tile_pip_decals(&self, x: i32, y: i32) -> Vec<Decal> {
+        self.tile_pip_decals(x, y)
+    }
 
-        while cursor_tile != self.chipdb.width * self.chipdb.height {
-            while cursor_tile < self.chipdb.num_tiles {
-                let Some(len) = self
-                    .chipdb
-                    .location_type
-                    .get(cursor_tile as usize)
-                    .and_then(|&t| self.chipdb.locations.get(t as usize))
-                    .map(|l| l.pip_data.len())
-                else {
-                    return vec![];
-                };
-                if cursor_index < len {
-                    break;
+    fn get_group_decals(&self) -> Vec<Decal> {
+        self.group_decals()
+    }
+
+    fn get_bel_site(&self, bel: &DecalID) -> Option<BelSite> {
+        if bel.r#type != decal::ECP5DecalType::TYPE_BEL {
+            return None;
+        }
+
+        let tile = bel.location.y * (self.chipdb.width as f64) + bel.location.x;
+        let bel_info = self
+            .chipdb
+            .location_type
+            .get(tile as usize)
+            .and_then(|&t| self.chipdb.locations.get(t as usize))
+            .and_then(|l| l.bel_data.get(bel.z as usize))?;
+
+        Some(BelSite {
+            x: bel.location.x as i32,
+            y: bel.location.y as i32,
+            r#type: format!("{:?}", bel_info.r#type),
+        })
+    }
+
+    fn get_routing_regions(&self) -> Vec<RoutingRegion> {
+        // One region per tile, in the same order as `location_type`
+        (0..self.chipdb.width * self.chipdb.height)
+            .map(|tile| {
+                let x = tile % self.chipdb.width;
+                let y = tile / self.chipdb.width;
+                let [_, wires, pips] = self.tile_len(x, y);
+
+                RoutingRegion {
+                    name: format!("X{x}/Y{y}"),
+                    x1: x as f64,
+                    y1: y as f64,
+                    x2: (x + 1) as f64,
+                    y2: (y + 1) as f64,
+                    pips: pips as u32,
+                    wires: wires as u32,
                 }
+            })
+            .collect()
+    }
 
-                cursor_index = 0;
-                cursor_tile += 1;
-            }
+    fn get_pip_region(&self, pip: &DecalID) -> Option<usize> {
+        if pip.r#type != decal::ECP5DecalType::TYPE_PIP {
+            return None;
+        }
 
-            let x = cursor_tile % self.chipdb.width;
-            let y = cursor_tile / self.chipdb.width;
+        Some((pip.location.y as i32 * self.chipdb.width + pip.location.x as i32) as usize)
+    }
 
-            ret.push(Decal::new(
-                ECP5DecalID::new(
-                    decal::ECP5DecalType::TYPE_PIP,
-                    x as f64,
-                    y as f64,
-                    cursor_index as f64,
-                ),
-                0.0,
-                0.0,
-                format!("TODO({cursor_tile}, {cursor_index})"),
-            ));
+    fn get_wire_region(&self, wire: &DecalID) -> Option<usize> {
+        if wire.r#type != decal::ECP5DecalType::TYPE_WIRE {
+            return None;
+        }
 
-            cursor_index += 1;
+        Some((wire.location.y as i32 * self.chipdb.width + wire.location.x as i32) as usize)
+    }
+
+    fn get_clock_network(&self) -> ClockNetwork {
+        let mut quadrants: FxHashMap<i8, (i32, i32, i32, i32)> = FxHashMap::default();
+        let mut taps: FxHashMap<(i16, i8, i8), (i32, i32)> = FxHashMap::default();
+        for (tile, glb) in self.chipdb.location_glbinfo.iter().enumerate() {
+            let x = tile as i32 % self.chipdb.width;
+            let y = tile as i32 / self.chipdb.width;
+
+            let quad = quadrants.entry(glb.quad).or_insert((x, y, x, y));
+            *quad = (quad.0.min(x), quad.1.min(y), quad.2.max(x), quad.3.max(y));
+
+            let tap = taps
+                .entry((glb.tap_col, glb.tap_dir, glb.quad))
+                .or_insert((y, y));
+            *tap = (tap.0.min(y), tap.1.max(y));
+        }
+
+        ClockNetwork {
+            quadrants: quadrants
+                .into_iter()
+                .sorted_by_key(|(quad, _)| *quad)
+                .map(|(_, (x1, y1, x2, y2))| ClockQuadrant { x1, y1, x2, y2 })
+                .collect(),
+            spines: self
+                .chipdb
+                .location_glbinfo
+                .iter()
+                .map(|glb| (glb.spine_col as i32, glb.spine_row as i32))
+                .sorted()
+                .dedup()
+                .map(|(x, y)| ClockSpine { x, y })
+                .collect(),
+            taps: taps
+                .into_iter()
+                .sorted()
+                .map(|((x, dir, _), (y1, y2))| ClockTap {
+                    x: x as i32,
+                    y1,
+                    y2,
+                    // nextpnr's `TAP_DIR_LEFT`
+                    left: dir == 0,
+                })
+                .collect(),
         }
-        ret
     }
 
-    fn get_group_decals(&self) -> Vec<Decal> {
-        let mut ret: Vec<Decal> = vec![];
+    fn is_global_buffer(&self, cell_type: &str) -> bool {
+        cell_type == "DCCA"
+    }
 
-        for y in 1..(self.chipdb.height - 1) {
-            for x in 1..(self.chipdb.width - 1) {
-                ret.push(Decal::new(
-                    DecalID::new(decal::ECP5DecalType::TYPE_GROUP, x as f64, y as f64, 1.0),
-                    0.0,
-                    0.0,
-                    format!("X{x}/Y{y}/UNKNOWN_GROUP"),
-                ))
-            }
+    fn get_clock_wire(&self, wire: &str) -> Option<ClockWire> {
+        let mut parts = wire.splitn(3, '/');
+        let x = parts.next()?.strip_prefix('X')?.parse().ok()?;
+        let y = parts.next()?.strip_prefix('Y')?.parse().ok()?;
+        let basename = parts.next()?;
+
+        if basename.starts_with("G_VPTX") {
+            Some(ClockWire::Spine { x, y })
+        } else if basename.starts_with("L_HPBX") || basename.starts_with("R_HPBX") {
+            let left = basename.starts_with('L');
+            Some(ClockWire::Tap { x, y, left })
+        } else {
+            None
         }
+    }
 
-        ret
+    fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        if !(0..self.chipdb.width).contains(&x) || !(0..self.chipdb.height).contains(&y) {
+            return vec![];
+        }
+
+        let Some(tile) = self
+            .chipdb
+            .tile_info
+            .get((y * self.chipdb.width + x) as usize)
+        else {
+            return vec![];
+        };
+
+        tile.tile_names
+            .iter()
+            .map(|t| {
+                let r#type = self
+                    .chipdb
+                    .tiletype_names
+                    .get(t.type_idx as usize)
+                    .map_or("?", |n| n.as_str());
+                format!("{}:{}", t.name, r#type)
+            })
+            .collect()
+    }
+
+    fn get_decal_tiles(&self, decal: &DecalID) -> Vec<String> {
+        self.get_tile_names(decal.location.x as i32, decal.location.y as i32)
+    }
+
+    fn get_speed_grades(&self) -> Vec<String> {
+        self.speed_grade_names()
+    }
+
+    fn get_decal_delays(&self, decal: &DecalID) -> Vec<ResourceDelay> {
+        self.pip_delays(decal)
+    }
+
+    fn get_cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.cell_delay(cell_type, from_port, to_port, speed)
+    }
+
+    fn get_cell_setup(
+        &self,
+        cell_type: &str,
+        port: &str,
+        clock_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.cell_setup(cell_type, port, clock_port, speed)
+    }
+
+    fn get_packages(&self) -> Vec<String> {
+        self.chipdb
+            .package_info
+            .iter()
+            .map(|p| p.name.clone())
+            .collect()
+    }
+
+    fn get_package_pins(&self, package: &str) -> Vec<PackagePin> {
+        let Some(package) = self.chipdb.package_info.iter().find(|p| p.name == package) else {
+            return vec![];
+        };
+
+        package
+            .pin_data
+            .iter()
+            .filter_map(|pin| {
+                let (x, y) = (pin.abs_loc.x as i32, pin.abs_loc.y as i32);
+                let bel_info = self
+                    .chipdb
+                    .location_type
+                    .get((y * self.chipdb.width + x) as usize)
+                    .and_then(|&t| self.chipdb.locations.get(t as usize))
+                    .and_then(|l| l.bel_data.get(pin.bel_index as usize))?;
+                let pio = self.chipdb.pio_info.iter().find(|pio| {
+                    pio.abs_loc.x == pin.abs_loc.x
+                        && pio.abs_loc.y == pin.abs_loc.y
+                        && pio.bel_index == pin.bel_index
+                });
+
+                Some(PackagePin {
+                    name: pin.name.clone(),
+                    bel: format!("X{}/Y{}/{}", x, y, bel_info.name),
+                    function: pio.map(|p| p.function_name.clone()),
+                    bank: pio.map(|p| p.bank.into()),
+                    dqs_group: pio.map(|p| p.dqsgroup).filter(|&g| g >= 0).map(Into::into),
+                })
+            })
+            .collect()
+    }
+
+    fn get_bel_pins(&self, bel: &DecalID) -> Vec<BelPin<DecalID>> {
+        if bel.r#type != decal::ECP5DecalType::TYPE_BEL {
+            return vec![];
+        }
+
+        let (x, y) = (bel.location.x as i32, bel.location.y as i32);
+        let Some(bel_data) = self
+            .location(x, y)
+            .and_then(|l| l.bel_data.get(bel.z as usize))
+        else {
+            return vec![];
+        };
+        let Some(bel_decal) = self.bel_decal(x, y, bel.z as usize) else {
+            return vec![];
+        };
+
+        bel_data
+            .bel_wires
+            .iter()
+            .filter_map(|w| {
+                let (wx, wy) = (x + w.rel_wire_loc.x as i32, y + w.rel_wire_loc.y as i32);
+                Some(BelPin {
+                    bel: bel_decal.clone(),
+                    pin: const_name(w.port),
+                    direction: port_direction(w.r#type),
+                    wire: self.wire_decal(wx, wy, w.wire_index as usize)?,
+                })
+            })
+            .collect()
+    }
+
+    fn get_wire_bel_pins(&self, wire: &DecalID) -> Vec<BelPin<DecalID>> {
+        if wire.r#type != decal::ECP5DecalType::TYPE_WIRE {
+            return vec![];
+        }
+
+        let (x, y) = (wire.location.x as i32, wire.location.y as i32);
+        let Some(wire_data) = self
+            .location(x, y)
+            .and_then(|l| l.wire_data.get(wire.z as usize))
+        else {
+            return vec![];
+        };
+        let Some(wire_decal) = self.wire_decal(x, y, wire.z as usize) else {
+            return vec![];
+        };
+
+        wire_data
+            .bel_pins
+            .iter()
+            .filter_map(|p| {
+                let (bx, by) = (x + p.rel_bel_loc.x as i32, y + p.rel_bel_loc.y as i32);
+                let bel_data = self.location(bx, by)?.bel_data.get(p.bel_index as usize)?;
+                let port_type = bel_data
+                    .bel_wires
+                    .iter()
+                    .find(|w| w.port == p.port)
+                    .map_or(2, |w| w.r#type);
+                Some(BelPin {
+                    bel: self.bel_decal(bx, by, p.bel_index as usize)?,
+                    pin: const_name(p.port),
+                    direction: port_direction(port_type),
+                    wire: wire_decal.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn get_pips_uphill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, true)
+    }
+
+    fn get_pips_downhill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, false)
+    }
+
+    fn get_pip_wires(&self, pip: &DecalID) -> Option<(Decal, Decal)> {
+        self.pip_wire_decals(pip)
     }
 
     fn find_pip_decal_by_loc_from_to(
@@ -389,26 +619,11 @@ impl Architecture<DecalID> for ECP5Arch {
             })
             .and_then(|v: Vec<usize>| v.first().cloned())?;
 
-        Some(Decal::new(
-            DecalID::new(
-                decal::ECP5DecalType::TYPE_PIP,
-                location.x as f64,
-                location.y as f64,
-                index as f64,
-            ),
-            0.0,
-            0.0,
-            format!(
-                "X{}/Y{};X{}/Y{}/{}->X{}/Y{}/{}",
-                location.x,
-                location.y,
-                from.location.x,
-                from.location.y,
-                from.name,
-                to.location.x,
-                to.location.y,
-                to.name
-            ),
-        ))
+        self.pip_decal(location.x as i32, location.y as i32, index)
+    }
+
+    fn find_pip_decal_by_name(&self, name: &str) -> Option<Decal> {
+        let (location, from, to) = parse_pip_name(name)?;
+        self.find_pip_decal_by_loc_from_to(&location, &from, &to)
     }
 }
@@ -1,20 +1,145 @@
-use super::types::{Architecture, Wire, WireLocation};
+use itertools::Itertools;
+
+use super::types::{
+    parse_pip_name, port_direction, Architecture, BelPin, BelSite, ClockNetwork, ClockWire,
+    PackagePin, ResourceDelay, RoutingRegion, Wire, WireLocation,
+};
 use crate::chipdb;
 use crate::decal;
 use crate::decal::ICE40GroupId;
 use crate::gfx;
-use crate::gfx::ice40::{consts, tile_pip, tile_wire, ConstId, GfxTileWireId};
+use crate::gfx::ice40::{
+    consts, get_wire_xy_local, get_wire_xy_main, tile_pip, tile_wire, ConstId, GfxTileWireId,
+};
+use crate::utils::log;
 
 type DecalID = decal::ICE40DecalID;
 type Decal = decal::DecalXY<DecalID>;
 
+fn const_name(id: i32) -> String {
+    format!("{:?}", ConstId::from(id as u32))
+}
+
 pub struct ICE40Arch {
     chipdb: chipdb::ice40::MinimizedChipInfoPOD,
+    /// Pip indices by the tile they are drawn in.
+    tile_pips: Vec<Vec<u32>>,
 }
 
+// Switchboxes per tile used as routing regions: main, local and one per LUT input
+const SWITCHBOXES_PER_TILE: usize = 10;
+
 impl ICE40Arch {
     pub fn new(chipdb: chipdb::ice40::MinimizedChipInfoPOD) -> Self {
-        ICE40Arch { chipdb }
+        let unknown: Vec<u32> = chipdb
+            .bel_data
+            .iter()
+            .filter_map(|b| match ConstId::from(b.r#type as u32) {
+                ConstId::Unknown(id) => Some(id),
+                _ => None,
+            })
+            .sorted()
+            .dedup()
+            .collect();
+        if !unknown.is_empty() {
+            log(format!(
+                "iCE40 chipdb has BEL types unknown to the viewer, drawn as placeholders: {unknown:?}"
+            ));
+        }
+
+        let mut tile_pips = vec![vec![]; (chipdb.width * chipdb.height).max(0) as usize];
+        for (index, pip) in chipdb.pip_data.iter().enumerate() {
+            let tile = pip.y as i32 * chipdb.width + pip.x as i32;
+            if let Some(pips) = tile_pips.get_mut(tile as usize) {
+                pips.push(index as u32);
+            }
+        }
+
+        ICE40Arch { chipdb, tile_pips }
+    }
+
+    // Mirrors the switchbox a pip is drawn in by `tile_pip`
+    fn pip_switchbox(&self, pip_index: usize) -> Option<decal::ICE40GroupType> {
+        let pip = self.chipdb.pip_data.get(pip_index)?;
+        let src = GfxTileWireId::from(pip.src_seg as u32);
+        let dst = GfxTileWireId::from(pip.dst_seg as u32);
+
+        if get_wire_xy_main(&src).is_some() && get_wire_xy_main(&dst).is_some() {
+            return Some(decal::ICE40GroupType::TYPE_MAIN_SW);
+        }
+
+        if get_wire_xy_local(&src).is_some() && get_wire_xy_local(&dst).is_some() {
+            return Some(decal::ICE40GroupType::TYPE_LOCAL_SW);
+        }
+
+        if GfxTileWireId::TILE_WIRE_LUTFF_0_IN_0 <= src
+            && src <= GfxTileWireId::TILE_WIRE_LUTFF_7_IN_3
+            && GfxTileWireId::TILE_WIRE_LUTFF_0_IN_0_LUT <= dst
+            && dst <= GfxTileWireId::TILE_WIRE_LUTFF_7_IN_3_LUT
+        {
+            let lut_idx = (src - GfxTileWireId::TILE_WIRE_LUTFF_0_IN_0) / 4;
+            return decal::ICE40GroupType::try_from(
+                decal::ICE40GroupType::TYPE_LC0_SW as i8 + lut_idx as i8,
+            )
+            .ok();
+        }
+
+        None
+    }
+
+    fn bel_decal(&self, index: usize) -> Option<Decal> {
+        let bel = self.chipdb.bel_data.get(index)?;
+        Some(Decal::new(
+            DecalID::new(decal::ICE40DecalType::TYPE_BEL, index as i32, false),
+            0.0,
+            0.0,
+            format!("X{}/Y{}/{}", bel.x, bel.y, bel.name),
+        ))
+    }
+
+    fn wire_decal(&self, index: usize) -> Option<Decal> {
+        let wire = self.chipdb.wire_data.get(index)?;
+        Some(Decal::new(
+            DecalID::new(decal::ICE40DecalType::TYPE_WIRE, index as i32, false),
+            0.0,
+            0.0,
+            format!("X{}/Y{}/{}", wire.x, wire.y, wire.name),
+        ))
+    }
+
+    /// Pip decal named after its tile and its source and destination wires.
+    fn pip_decal(&self, index: usize, active: bool) -> Option<Decal> {
+        let pip = self.chipdb.pip_data.get(index)?;
+        let src = self.chipdb.wire_data.get(pip.src as usize)?;
+        let dst = self.chipdb.wire_data.get(pip.dst as usize)?;
+
+        Some(Decal::new(
+            DecalID::new(decal::ICE40DecalType::TYPE_PIP, index as i32, active),
+            0.0,
+            0.0,
+            format!(
+                "X{}/Y{};X{}/Y{}/{}->X{}/Y{}/{}",
+                pip.x, pip.y, src.x, src.y, src.name, dst.x, dst.y, dst.name
+            ),
+        ))
+    }
+
+    fn wire_pips(&self, wire: &DecalID, uphill: bool) -> Vec<Decal> {
+        if wire.r#type != decal::ICE40DecalType::TYPE_WIRE {
+            return vec![];
+        }
+        let Some(wire_data) = self.chipdb.wire_data.get(wire.index as usize) else {
+            return vec![];
+        };
+        let pips = if uphill {
+            &wire_data.pips_uphill
+        } else {
+            &wire_data.pips_downhill
+        };
+
+        pips.iter()
+            .filter_map(|&p| self.pip_decal(p as usize, false))
+            .collect()
     }
 }
 
@@ -29,50 +154,7 @@ impl Architecture<DecalID> for ICE40Arch {
             let y = (decal.index & 255) as f64;
 
             if r#type == decal::ICE40GroupType::TYPE_FRAME {
-                let mut el = gfx::GraphicElement::new(gfx::Type::Line, gfx::Style::Frame);
-                el.x1 = x + 0.01;
-                el.x2 = x + 0.02;
-                el.y1 = y + 0.01;
-                el.y2 = y + 0.01;
-                g.push(el);
-                el.x1 = x + 0.01;
-                el.x2 = x + 0.01;
-                el.y1 = y + 0.01;
-                el.y2 = y + 0.02;
-                g.push(el);
-
-                el.x1 = x + 0.99;
-                el.x2 = x + 0.98;
-                el.y1 = y + 0.01;
-                el.y2 = y + 0.01;
-                g.push(el);
-                el.x1 = x + 0.99;
-                el.x2 = x + 0.99;
-                el.y1 = y + 0.01;
-                el.y2 = y + 0.02;
-                g.push(el);
-
-                el.x1 = x + 0.99;
-                el.x2 = x + 0.98;
-                el.y1 = y + 0.99;
-                el.y2 = y + 0.99;
-                g.push(el);
-                el.x1 = x + 0.99;
-                el.x2 = x + 0.99;
-                el.y1 = y + 0.99;
-                el.y2 = y + 0.98;
-                g.push(el);
-
-                el.x1 = x + 0.01;
-                el.x2 = x + 0.02;
-                el.y1 = y + 0.99;
-                el.y2 = y + 0.99;
-                g.push(el);
-                el.x1 = x + 0.01;
-                el.x2 = x + 0.01;
-                el.y1 = y + 0.99;
-                el.y2 = y + 0.98;
-                g.push(el);
+                g.extend(gfx::tile_frame(x, y));
             }
 
             if r#type == decal::ICE40GroupType::TYPE_MAIN_SW {
@@ -127,7 +209,7 @@ impl Architecture<DecalID> for ICE40Arch {
                     segment.y.into(),
                     self.chipdb.width,
                     self.chipdb.height,
-                    &GfxTileWireId::try_from(segment.index as u32).unwrap(),
+                    &GfxTileWireId::from(segment.index as u32),
                     &style,
                 );
             }
@@ -144,16 +226,15 @@ impl Architecture<DecalID> for ICE40Arch {
                 &mut g,
                 p.x.into(),
                 p.y.into(),
-                &GfxTileWireId::try_from(p.src_seg as u32).unwrap(),
-                &GfxTileWireId::try_from(p.dst_seg as u32).unwrap(),
+                &GfxTileWireId::from(p.src_seg as u32),
+                &GfxTileWireId::from(p.dst_seg as u32),
                 &style,
             );
         }
 
         if decal.r#type == decal::ICE40DecalType::TYPE_BEL {
             let bel_index = decal.index as usize;
-            let bel_type =
-                ConstId::try_from(self.chipdb.bel_data[bel_index].r#type as u32).unwrap();
+            let bel_type = ConstId::from(self.chipdb.bel_data[bel_index].r#type as u32);
 
             if bel_type == ConstId::ICESTORM_LC {
                 let mut el = gfx::GraphicElement::new(
@@ -248,6 +329,61 @@ impl Architecture<DecalID> for ICE40Arch {
                 el.y2 = self.chipdb.bel_data[bel_index].y as f64 + consts::main_swbox_y2 + 0.05;
                 g.push(el);
             }
+
+            let style = if decal.active {
+                gfx::Style::Active
+            } else {
+                gfx::Style::Inactive
+            };
+            let bel = &self.chipdb.bel_data[bel_index];
+
+            if bel_type == ConstId::ICESTORM_DSP {
+                // A DSP spans its four DSP tiles, drawn like the halves of a RAM
+                for i in 0..4 {
+                    let mut el = gfx::GraphicElement::new(gfx::Type::Box, style);
+                    el.x1 = bel.x as f64 + consts::lut_swbox_x1;
+                    el.x2 = bel.x as f64 + consts::logic_cell_x2;
+                    el.y1 = bel.y as f64 + consts::logic_cell_y1 + i as f64;
+                    el.y2 = bel.y as f64
+                        + consts::logic_cell_y2
+                        + i as f64
+                        + 7.0 * consts::logic_cell_pitch;
+                    g.push(el);
+                }
+            }
+
+            // UltraPlus hard IP shares the corner tiles, stacked by z like the IOs
+            let slot = (bel.z % 5) as f64;
+            let (x1, x2) = (
+                bel.x as f64 + consts::lut_swbox_x1,
+                bel.x as f64 + consts::logic_cell_x2,
+            );
+            let y1 = bel.y as f64 + consts::logic_cell_y1 + 3.0 * slot * consts::logic_cell_pitch;
+            let y2 = bel.y as f64
+                + consts::logic_cell_y2
+                + (3.0 * slot + 1.0) * consts::logic_cell_pitch;
+
+            if matches!(
+                bel_type,
+                ConstId::ICESTORM_SPRAM
+                    | ConstId::ICESTORM_HFOSC
+                    | ConstId::ICESTORM_LFOSC
+                    | ConstId::SB_I2C
+                    | ConstId::SB_SPI
+                    | ConstId::IO_I3C
+                    | ConstId::SB_LEDDA_IP
+                    | ConstId::SB_RGBA_DRV
+                    | ConstId::SB_RGB_DRV
+                    | ConstId::SB_LED_DRV_CUR
+            ) {
+                let mut el = gfx::GraphicElement::new(gfx::Type::Box, style);
+                (el.x1, el.y1, el.x2, el.y2) = (x1, y1, x2, y2);
+                g.push(el);
+            }
+
+            if g.is_empty() {
+                g = gfx::unknown_bel(x1, y1, x2, y2, style);
+            }
         }
 
         g
@@ -285,19 +421,17 @@ impl Architecture<DecalID> for ICE40Arch {
             .collect()
     }
 
-    fn get_pip_decals(&self) -> Vec<Decal> {
-        self.chipdb
-            .pip_data
+    fn get_pip_decals(&self) -> Box<dyn Iterator<Item = Decal> + '_> {
+        Box::new((0..self.chipdb.pip_data.len()).filter_map(|i| self.pip_decal(i, false)))
+    }
+
+    fn get_tile_pip_decals(&self, x: i32, y: i32) -> Vec<Decal> {
+        if x < 0 || y < 0 || x >= self.chipdb.width || y >= self.chipdb.height {
+            return vec![];
+        }
+        self.tile_pips[(y * self.chipdb.width + x) as usize]
             .iter()
-            .enumerate()
-            .map(|(i, pip)| {
-                Decal::new(
-                    DecalID::new(decal::ICE40DecalType::TYPE_PIP, i as i32, false),
-                    0.0,
-                    0.0,
-                    format!("X{}/Y{}", pip.x, pip.y),
-                )
-            })
+            .filter_map(|&i| self.pip_decal(i as usize, false))
             .collect()
     }
 
@@ -367,6 +501,299 @@ impl Architecture<DecalID> for ICE40Arch {
             .collect()
     }
 
+    fn get_bel_site(&self, bel: &DecalID) -> Option<BelSite> {
+        if bel.r#type != decal::ICE40DecalType::TYPE_BEL {
+            return None;
+        }
+
+        let bel_info = self.chipdb.bel_data.get(bel.index as usize)?;
+        Some(BelSite {
+            x: bel_info.x.into(),
+            y: bel_info.y.into(),
+            r#type: const_name(bel_info.r#type),
+        })
+    }
+
+    fn get_routing_regions(&self) -> Vec<RoutingRegion> {
+        let mut pips =
+            vec![0u32; (self.chipdb.width * self.chipdb.height) as usize * SWITCHBOXES_PER_TILE];
+        for i in 0..self.chipdb.pip_data.len() {
+            let decal = DecalID::new(decal::ICE40DecalType::TYPE_PIP, i as i32, false);
+            if let Some(region) = self.get_pip_region(&decal) {
+                pips[region] += 1;
+            }
+        }
+
+        pips.iter()
+            .enumerate()
+            .map(|(region, &pips)| {
+                let tile = (region / SWITCHBOXES_PER_TILE) as i32;
+                let x = tile % self.chipdb.width;
+                let y = tile / self.chipdb.width;
+                let group = decal::ICE40GroupId::new(
+                    decal::ICE40GroupType::try_from(
+                        (decal::ICE40GroupType::TYPE_MAIN_SW as usize
+                            + region % SWITCHBOXES_PER_TILE) as i8,
+                    )
+                    .unwrap_or(decal::ICE40GroupType::TYPE_NONE),
+                    x as i8,
+                    y as i8,
+                );
+
+                // Shade the same area as the switchbox group decal
+                let index = ((group.r#type as i32 & 255) << 16) ^ ((x & 255) << 8) ^ (y & 255);
+                let ge = self.get_decal_graphics(&DecalID::new(
+                    decal::ICE40DecalType::TYPE_GROUP,
+                    index,
+                    false,
+                ));
+                let (x1, y1, x2, y2) = ge.first().map(|e| (e.x1, e.y1, e.x2, e.y2)).unwrap_or((
+                    x as f64,
+                    y as f64,
+                    (x + 1) as f64,
+                    (y + 1) as f64,
+                ));
+
+                RoutingRegion {
+                    name: format!("X{}/Y{}/{}", x, y, group.name()),
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    pips,
+                    // Wires run between switchboxes, so they are not counted per region
+                    wires: 0,
+                }
+            })
+            .collect()
+    }
+
+    fn get_pip_region(&self, pip: &DecalID) -> Option<usize> {
+        if pip.r#type != decal::ICE40DecalType::TYPE_PIP {
+            return None;
+        }
+
+        let switchbox = self.pip_switchbox(pip.index as usize)?;
+        let pip = &self.chipdb.pip_data[pip.index as usize];
+        let tile = pip.y as usize * self.chipdb.width as usize + pip.x as usize;
+
+        Some(
+            tile * SWITCHBOXES_PER_TILE
+                + (switchbox - decal::ICE40GroupType::TYPE_MAIN_SW) as usize,
+        )
+    }
+
+    fn get_wire_region(&self, _wire: &DecalID) -> Option<usize> {
+        None
+    }
+
+    fn get_clock_network(&self) -> ClockNetwork {
+        // iCE40 global nets reach every tile directly, there are no spines or taps to show
+        ClockNetwork::default()
+    }
+
+    fn is_global_buffer(&self, cell_type: &str) -> bool {
+        cell_type == "SB_GB"
+    }
+
+    fn get_clock_wire(&self, _wire: &str) -> Option<ClockWire> {
+        None
+    }
+
+    fn get_tile_names(&self, _x: i32, _y: i32) -> Vec<String> {
+        vec![]
+    }
+
+    fn get_decal_tiles(&self, _decal: &DecalID) -> Vec<String> {
+        vec![]
+    }
+
+    fn get_speed_grades(&self) -> Vec<String> {
+        // HX parts use the fast delays, LP and UP parts the slow ones
+        vec!["fast".to_string(), "slow".to_string()]
+    }
+
+    fn get_decal_delays(&self, decal: &DecalID) -> Vec<ResourceDelay> {
+        let delays = match decal.r#type {
+            decal::ICE40DecalType::TYPE_PIP => self
+                .chipdb
+                .pip_data
+                .get(decal.index as usize)
+                .map(|p| (p.fast_delay, p.slow_delay)),
+            decal::ICE40DecalType::TYPE_WIRE => self
+                .chipdb
+                .wire_data
+                .get(decal.index as usize)
+                .map(|w| (w.fast_delay, w.slow_delay)),
+            _ => None,
+        };
+        let Some((fast, slow)) = delays else {
+            return vec![];
+        };
+
+        self.get_speed_grades()
+            .into_iter()
+            .zip([fast, slow])
+            .map(|(speed, delay)| ResourceDelay {
+                speed,
+                min: delay,
+                max: delay,
+                fanout_adder: 0,
+            })
+            .collect()
+    }
+
+    fn get_cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.chipdb
+            .cell_timing
+            .iter()
+            .find(|t| const_name(t.r#type) == cell_type)?
+            .path_delays
+            .iter()
+            .find(|d| const_name(d.from_port) == from_port && const_name(d.to_port) == to_port)
+            .map(|d| match speed {
+                "fast" => d.fast_delay,
+                _ => d.slow_delay,
+            })
+    }
+
+    fn get_cell_setup(
+        &self,
+        _cell_type: &str,
+        _port: &str,
+        _clock_port: &str,
+        _speed: &str,
+    ) -> Option<i32> {
+        // The iCE40 chipdb has no setup times, nextpnr hardcodes them
+        None
+    }
+
+    fn get_packages(&self) -> Vec<String> {
+        self.chipdb
+            .packages_data
+            .iter()
+            .map(|p| p.name.clone())
+            .collect()
+    }
+
+    fn get_package_pins(&self, package: &str) -> Vec<PackagePin> {
+        let Some(package) = self.chipdb.packages_data.iter().find(|p| p.name == package) else {
+            return vec![];
+        };
+
+        package
+            .pins
+            .iter()
+            .filter_map(|pin| {
+                let bel = self.chipdb.bel_data.get(pin.bel_index as usize)?;
+                let (x, y) = (bel.x as i32, bel.y as i32);
+
+                // Banks are numbered clockwise from the top edge
+                let bank = if y == self.chipdb.height - 1 {
+                    Some(0)
+                } else if x == self.chipdb.width - 1 {
+                    Some(1)
+                } else if y == 0 {
+                    Some(2)
+                } else if x == 0 {
+                    Some(3)
+                } else {
+                    None
+                };
+
+                Some(PackagePin {
+                    name: pin.name.clone(),
+                    bel: format!("X{}/Y{}/{}", x, y, bel.name),
+                    function: None,
+                    bank,
+                    dqs_group: None,
+                })
+            })
+            .collect()
+    }
+
+    fn get_bel_pins(&self, bel: &DecalID) -> Vec<BelPin<DecalID>> {
+        if bel.r#type != decal::ICE40DecalType::TYPE_BEL {
+            return vec![];
+        }
+        let (Some(bel_data), Some(bel_decal)) = (
+            self.chipdb.bel_data.get(bel.index as usize),
+            self.bel_decal(bel.index as usize),
+        ) else {
+            return vec![];
+        };
+
+        bel_data
+            .bel_wires
+            .iter()
+            .filter_map(|w| {
+                Some(BelPin {
+                    bel: bel_decal.clone(),
+                    pin: const_name(w.port),
+                    direction: port_direction(w.r#type),
+                    wire: self.wire_decal(w.wire_index as usize)?,
+                })
+            })
+            .collect()
+    }
+
+    fn get_wire_bel_pins(&self, wire: &DecalID) -> Vec<BelPin<DecalID>> {
+        if wire.r#type != decal::ICE40DecalType::TYPE_WIRE {
+            return vec![];
+        }
+        let (Some(wire_data), Some(wire_decal)) = (
+            self.chipdb.wire_data.get(wire.index as usize),
+            self.wire_decal(wire.index as usize),
+        ) else {
+            return vec![];
+        };
+
+        wire_data
+            .bel_pins
+            .iter()
+            .filter_map(|p| {
+                let bel_data = self.chipdb.bel_data.get(p.bel_index as usize)?;
+                let port_type = bel_data
+                    .bel_wires
+                    .iter()
+                    .find(|w| w.port == p.port)
+                    .map_or(2, |w| w.r#type);
+                Some(BelPin {
+                    bel: self.bel_decal(p.bel_index as usize)?,
+                    pin: const_name(p.port),
+                    direction: port_direction(port_type),
+                    wire: wire_decal.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn get_pips_uphill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, true)
+    }
+
+    fn get_pips_downhill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, false)
+    }
+
+    fn get_pip_wires(&self, pip: &DecalID) -> Option<(Decal, Decal)> {
+        if pip.r#type != decal::ICE40DecalType::TYPE_PIP {
+            return None;
+        }
+
+        let pip_data = self.chipdb.pip_data.get(pip.index as usize)?;
+        Some((
+            self.wire_decal(pip_data.src as usize)?,
+            self.wire_decal(pip_data.dst as usize)?,
+        ))
+    }
+
     fn find_pip_decal_by_loc_from_to(
         &self,
         location: &WireLocation,
@@ -380,42 +807,30 @@ impl Architecture<DecalID> for ICE40Arch {
             .enumerate()
             .filter(|(_i, pip)| {
                 // Pip location
-                location.x == pip.x.into() && location.y == pip.y.into()
+                location.x == i16::from(pip.x) && location.y == i16::from(pip.y)
             })
             .filter(|(_i, pip)| {
                 // Pip source wire
                 let src_wire = &self.chipdb.wire_data[pip.src as usize];
-                from.location.x == src_wire.x.into()
-                    && from.location.y == src_wire.y.into()
+                from.location.x == i16::from(src_wire.x)
+                    && from.location.y == i16::from(src_wire.y)
                     && src_wire.name == from.name
             })
             .filter(|(_i, pip)| {
                 // Pip dest wire
                 let dst_wire = &self.chipdb.wire_data[pip.dst as usize];
-                to.location.x == dst_wire.x.into()
-                    && to.location.y == dst_wire.y.into()
+                to.location.x == i16::from(dst_wire.x)
+                    && to.location.y == i16::from(dst_wire.y)
                     && dst_wire.name == to.name
             })
             .map(|(i, _)| i)
             .collect::<Vec<_>>();
 
-        let index = *pips.first()? as i32;
+        self.pip_decal(*pips.first()?, true)
+    }
 
-        Some(Decal::new(
-            DecalID::new(decal::ICE40DecalType::TYPE_PIP, index, true),
-            0.0,
-            0.0,
-            format!(
-                "X{}/Y{};X{}/Y{}/{}->X{}/Y{}/{}",
-                location.x,
-                location.y,
-                from.location.x,
-                from.location.y,
-                from.name,
-                to.location.x,
-                to.location.y,
-                to.name
-            ),
-        ))
+    fn find_pip_decal_by_name(&self, name: &str) -> Option<Decal> {
+        let (location, from, to) = parse_pip_name(name)?;
+        self.find_pip_decal_by_loc_from_to(&location, &from, &to)
     }
 }
@@ -0,0 +1,582 @@
+use itertools::Itertools;
+
+use super::trellis::{Trellis, TrellisPip};
+use super::types::{
+    parse_pip_name, port_direction, Architecture, BelPin, BelSite, ClockNetwork, ClockWire,
+    PackagePin, ResourceDelay, RoutingRegion, Wire, WireLocation,
+};
+use crate::chipdb;
+use crate::decal;
+use crate::gfx;
+use crate::gfx::ecp5::{ConstId, GfxTileWireId};
+use crate::utils::log;
+
+// MachXO2 tiles come from the same Trellis database as ECP5, so they share its decals and gfx
+type DecalID = decal::ECP5DecalID;
+type Decal = decal::DecalXY<DecalID>;
+
+pub struct MachXO2Arch {
+    chipdb: chipdb::machxo2::MinimizedChipDatabase,
+    /// ECP5 wire type and tile wire of every wire, by tile. The chipdb numbers them after
+    /// nextpnr's MachXO2 tables, so they are matched to the ECP5 ones by name.
+    wire_gfx: Vec<Vec<(ConstId, GfxTileWireId)>>,
+}
+
+impl MachXO2Arch {
+    pub fn new(chipdb: chipdb::machxo2::MinimizedChipDatabase) -> Self {
+        let mut arch = MachXO2Arch {
+            chipdb,
+            wire_gfx: vec![],
+        };
+
+        arch.wire_gfx = arch
+            .chipdb
+            .chip
+            .tiles
+            .iter()
+            .map(|t| {
+                t.wire_data
+                    .iter()
+                    .map(|w| {
+                        let tile_wire = format!("TILE_WIRE_{}", w.name);
+                        (
+                            arch.ecp5_const(w.r#type),
+                            GfxTileWireId::from_name(&tile_wire)
+                                .unwrap_or(GfxTileWireId::TILE_WIRE_NONE),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let unknown: Vec<&str> = arch
+            .chipdb
+            .chip
+            .tiles
+            .iter()
+            .flat_map(|t| &t.bel_data)
+            .map(|b| arch.const_name(b.r#type))
+            .filter(|name| ConstId::from_name(name).is_none())
+            .sorted()
+            .dedup()
+            .collect();
+        if !unknown.is_empty() {
+            log(format!(
+                "MachXO2 chipdb has BEL types without ECP5 graphics, drawn as placeholders: {unknown:?}"
+            ));
+        }
+
+        arch
+    }
+
+    fn const_name(&self, id: i32) -> &str {
+        self.chipdb
+            .id_strs
+            .get(id as usize)
+            .map_or("?", |s| s.as_str())
+    }
+
+    fn ecp5_const(&self, id: i32) -> ConstId {
+        ConstId::from_name(self.const_name(id)).unwrap_or(ConstId::Unknown(id as u32))
+    }
+
+    fn location(&self, x: i32, y: i32) -> Option<&chipdb::machxo2::MinimizedTileTypePOD> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return None;
+        }
+        self.chipdb.chip.tiles.get((y * self.width() + x) as usize)
+    }
+}
+
+impl Trellis for MachXO2Arch {
+    // nextpnr-machxo2's `--speed` values, which index the chipdb's speed grades
+    const SPEED_GRADE_NAMES: &'static [&'static str] = &["1", "2", "3", "4", "5", "6"];
+
+    fn width(&self) -> i32 {
+        self.chipdb.chip.width
+    }
+
+    fn height(&self) -> i32 {
+        self.chipdb.chip.height
+    }
+
+    fn tile_len(&self, x: i32, y: i32) -> [usize; 3] {
+        self.location(x, y).map_or([0; 3], |l| {
+            [l.bel_data.len(), l.wire_data.len(), l.pip_data.len()]
+        })
+    }
+
+    fn bel_name(&self, x: i32, y: i32, index: usize) -> Option<&str> {
+        Some(&self.location(x, y)?.bel_data.get(index)?.name)
+    }
+
+    fn bel_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, i32)> {
+        let bel = self.location(x, y)?.bel_data.get(index)?;
+        Some((self.ecp5_const(bel.r#type), bel.z))
+    }
+
+    fn wire_name(&self, x: i32, y: i32, index: usize) -> Option<&str> {
+        Some(&self.location(x, y)?.wire_data.get(index)?.name)
+    }
+
+    fn wire_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, GfxTileWireId)> {
+        self.wire_gfx
+            .get((y * self.width() + x) as usize)?
+            .get(index)
+            .copied()
+    }
+
+    fn wire_pip_locators(
+        &self,
+        x: i32,
+        y: i32,
+        index: usize,
+        uphill: bool,
+    ) -> Option<&[chipdb::ecp5::MinimizedPipLocatorPOD]> {
+        let wire = self.location(x, y)?.wire_data.get(index)?;
+        Some(if uphill {
+            &wire.pips_uphill
+        } else {
+            &wire.pips_downhill
+        })
+    }
+
+    fn pip(&self, x: i32, y: i32, index: usize) -> Option<TrellisPip> {
+        let pip = self.location(x, y)?.pip_data.get(index)?;
+        let (src, dst) = (&pip.rel_src_loc, &pip.rel_dst_loc);
+        Some(TrellisPip {
+            src: (src.x as i32, src.y as i32, pip.src_idx as usize),
+            dst: (dst.x as i32, dst.y as i32, pip.dst_idx as usize),
+            timing_class: pip.timing_class as usize,
+        })
+    }
+
+    fn speed_grades(&self) -> &[chipdb::ecp5::MinimizedSpeedGradePOD] {
+        &self.chipdb.chip.speed_grades
+    }
+
+    fn id_name(&self, id: i32) -> String {
+        self.const_name(id).to_string()
+    }
+}
+
+impl Architecture<DecalID> for MachXO2Arch {
+    fn get_decal_graphics(&self, decal: &DecalID) -> Vec<gfx::GraphicElement> {
+        self.decal_graphics(decal)
+    }
+
+    fn get_bel_decals(&self) -> Vec<Decal> {
+        let width = self.width();
+        (0..width * self.height())
+            .flat_map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let len = self.location(x, y).map_or(0, |l| l.bel_data.len());
+                (0..len).filter_map(move |index| self.bel_decal(x, y, index))
+            })
+            .collect()
+    }
+
+    fn get_wire_decals(&self) -> Vec<Decal> {
+        let width = self.width();
+        (0..width * self.height())
+            .flat_map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let len = self.location(x, y).map_or(0, |l| l.wire_data.len());
+                (0..len).filter_map(move |index| self.wire_decal(x, y, index))
+            })
+            .collect()
+    }
+
+    fn get_pip_decals(&self) -> Box<dyn Iterator<Item = Decal> + '_> {
+        let width = self.width();
+        Box::new((0..width * self.height()).flat_map(move |tile| {
+            let (x, y) = (tile % width, tile / width);
+            let len = self.location(x, y).map_or(0, |l| l.pip_data.len());
+            (0..len).filter_map(move |index| self.pip_decal(x, y, index))
+        }))
+    }
+
+    fn get_tile_pip_decals(&self, x: i32, y: i32) -> Vec<Decal> {
+        self.tile_pip_decals(x, y)
+    }
+
+    fn get_group_decals(&self) -> Vec<Decal> {
+        self.group_decals()
+    }
+
+    fn get_bel_site(&self, bel: &DecalID) -> Option<BelSite> {
+        if bel.r#type != decal::ECP5DecalType::TYPE_BEL {
+            return None;
+        }
+
+        let (x, y) = (bel.location.x as i32, bel.location.y as i32);
+        let bel_info = self.location(x, y)?.bel_data.get(bel.z as usize)?;
+        Some(BelSite {
+            x,
+            y,
+            r#type: self.const_name(bel_info.r#type).to_string(),
+        })
+    }
+
+    fn get_routing_regions(&self) -> Vec<RoutingRegion> {
+        // One region per tile, in the same order as `tiles`
+        let width = self.width();
+        (0..width * self.height())
+            .map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let [_, wires, pips] = self.tile_len(x, y);
+
+                RoutingRegion {
+                    name: format!("X{x}/Y{y}"),
+                    x1: x as f64,
+                    y1: y as f64,
+                    x2: (x + 1) as f64,
+                    y2: (y + 1) as f64,
+                    pips: pips as u32,
+                    wires: wires as u32,
+                }
+            })
+            .collect()
+    }
+
+    fn get_pip_region(&self, pip: &DecalID) -> Option<usize> {
+        if pip.r#type != decal::ECP5DecalType::TYPE_PIP {
+            return None;
+        }
+
+        Some((pip.location.y as i32 * self.width() + pip.location.x as i32) as usize)
+    }
+
+    fn get_wire_region(&self, wire: &DecalID) -> Option<usize> {
+        if wire.r#type != decal::ECP5DecalType::TYPE_WIRE {
+            return None;
+        }
+
+        Some((wire.location.y as i32 * self.width() + wire.location.x as i32) as usize)
+    }
+
+    fn get_clock_network(&self) -> ClockNetwork {
+        // The MachXO2 chipdb has no global clock topology like ECP5's `location_glbinfo`
+        ClockNetwork::default()
+    }
+
+    fn is_global_buffer(&self, cell_type: &str) -> bool {
+        cell_type == "DCCA"
+    }
+
+    fn get_clock_wire(&self, _wire: &str) -> Option<ClockWire> {
+        None
+    }
+
+    fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        if !(0..self.width()).contains(&x) || !(0..self.height()).contains(&y) {
+            return vec![];
+        }
+
+        let Some(tile) = self
+            .chipdb
+            .chip
+            .tile_info
+            .get((y * self.width() + x) as usize)
+        else {
+            return vec![];
+        };
+
+        tile.tile_names
+            .iter()
+            .map(|t| {
+                let r#type = self
+                    .chipdb
+                    .chip
+                    .tiletype_names
+                    .get(t.type_idx as usize)
+                    .map_or("?", |n| n.as_str());
+                format!("{}:{}", t.name, r#type)
+            })
+            .collect()
+    }
+
+    fn get_decal_tiles(&self, decal: &DecalID) -> Vec<String> {
+        self.get_tile_names(decal.location.x as i32, decal.location.y as i32)
+    }
+
+    fn get_speed_grades(&self) -> Vec<String> {
+        self.speed_grade_names()
+    }
+
+    fn get_decal_delays(&self, decal: &DecalID) -> Vec<ResourceDelay> {
+        self.pip_delays(decal)
+    }
+
+    fn get_cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.cell_delay(cell_type, from_port, to_port, speed)
+    }
+
+    fn get_cell_setup(
+        &self,
+        cell_type: &str,
+        port: &str,
+        clock_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.cell_setup(cell_type, port, clock_port, speed)
+    }
+
+    fn get_packages(&self) -> Vec<String> {
+        self.chipdb
+            .chip
+            .package_info
+            .iter()
+            .map(|p| p.name.clone())
+            .collect()
+    }
+
+    fn get_package_pins(&self, package: &str) -> Vec<PackagePin> {
+        let Some(package) = self
+            .chipdb
+            .chip
+            .package_info
+            .iter()
+            .find(|p| p.name == package)
+        else {
+            return vec![];
+        };
+
+        package
+            .pin_data
+            .iter()
+            .filter_map(|pin| {
+                let (x, y) = (pin.abs_loc.x as i32, pin.abs_loc.y as i32);
+                let bel = self.bel_decal(x, y, pin.bel_index as usize)?;
+                let pio = self.chipdb.chip.pio_info.iter().find(|pio| {
+                    pio.abs_loc.x == pin.abs_loc.x
+                        && pio.abs_loc.y == pin.abs_loc.y
+                        && pio.bel_index == pin.bel_index
+                });
+
+                Some(PackagePin {
+                    name: pin.name.clone(),
+                    bel: bel.id,
+                    function: pio.map(|p| p.function_name.clone()),
+                    bank: pio.map(|p| p.bank.into()),
+                    dqs_group: pio.map(|p| p.dqsgroup).filter(|&g| g >= 0).map(Into::into),
+                })
+            })
+            .collect()
+    }
+
+    fn get_bel_pins(&self, bel: &DecalID) -> Vec<BelPin<DecalID>> {
+        if bel.r#type != decal::ECP5DecalType::TYPE_BEL {
+            return vec![];
+        }
+
+        let (x, y) = (bel.location.x as i32, bel.location.y as i32);
+        let Some(bel_data) = self
+            .location(x, y)
+            .and_then(|l| l.bel_data.get(bel.z as usize))
+        else {
+            return vec![];
+        };
+        let Some(bel_decal) = self.bel_decal(x, y, bel.z as usize) else {
+            return vec![];
+        };
+
+        bel_data
+            .bel_wires
+            .iter()
+            .filter_map(|w| {
+                let (wx, wy) = (x + w.rel_wire_loc.x as i32, y + w.rel_wire_loc.y as i32);
+                Some(BelPin {
+                    bel: bel_decal.clone(),
+                    pin: self.const_name(w.port).to_string(),
+                    direction: port_direction(w.r#type),
+                    wire: self.wire_decal(wx, wy, w.wire_index as usize)?,
+                })
+            })
+            .collect()
+    }
+
+    fn get_wire_bel_pins(&self, wire: &DecalID) -> Vec<BelPin<DecalID>> {
+        if wire.r#type != decal::ECP5DecalType::TYPE_WIRE {
+            return vec![];
+        }
+
+        let (x, y) = (wire.location.x as i32, wire.location.y as i32);
+        let Some(wire_data) = self
+            .location(x, y)
+            .and_then(|l| l.wire_data.get(wire.z as usize))
+        else {
+            return vec![];
+        };
+        let Some(wire_decal) = self.wire_decal(x, y, wire.z as usize) else {
+            return vec![];
+        };
+
+        wire_data
+            .bel_pins
+            .iter()
+            .filter_map(|p| {
+                let (bx, by) = (x + p.rel_bel_loc.x as i32, y + p.rel_bel_loc.y as i32);
+                let bel_data = self.location(bx, by)?.bel_data.get(p.bel_index as usize)?;
+                let port_type = bel_data
+                    .bel_wires
+                    .iter()
+                    .find(|w| w.port == p.port)
+                    .map_or(2, |w| w.r#type);
+                Some(BelPin {
+                    bel: self.bel_decal(bx, by, p.bel_index as usize)?,
+                    pin: self.const_name(p.port).to_string(),
+                    direction: port_direction(port_type),
+                    wire: wire_decal.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn get_pips_uphill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, true)
+    }
+
+    fn get_pips_downhill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, false)
+    }
+
+    fn get_pip_wires(&self, pip: &DecalID) -> Option<(Decal, Decal)> {
+        self.pip_wire_decals(pip)
+    }
+
+    fn find_pip_decal_by_loc_from_to(
+        &self,
+        location: &WireLocation,
+        from: &Wire,
+        to: &Wire,
+    ) -> Option<Decal> {
+        let (x, y) = (location.x as i32, location.y as i32);
+        let wire_name = |dx: i16, dy: i16, index: i32| {
+            self.location(x + dx as i32, y + dy as i32)
+                .and_then(|l| l.wire_data.get(index as usize))
+                .map(|w| w.name.as_str())
+        };
+
+        let index = self.location(x, y)?.pip_data.iter().position(|p| {
+            p.rel_src_loc.x == from.location.x
+                && p.rel_src_loc.y == from.location.y
+                && p.rel_dst_loc.x == to.location.x
+                && p.rel_dst_loc.y == to.location.y
+                && wire_name(p.rel_src_loc.x, p.rel_src_loc.y, p.src_idx)
+                    == Some(from.name.as_str())
+                && wire_name(p.rel_dst_loc.x, p.rel_dst_loc.y, p.dst_idx) == Some(to.name.as_str())
+        })?;
+        self.pip_decal(x, y, index)
+    }
+
+    fn find_pip_decal_by_name(&self, name: &str) -> Option<Decal> {
+        let (location, from, to) = parse_pip_name(name)?;
+        self.find_pip_decal_by_loc_from_to(&location, &from, &to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chipdb::machxo2::fixture;
+
+    /// The fixture chipdb, minimized and loaded like the viewer does.
+    fn arch() -> MachXO2Arch {
+        let const_ids = fixture::CONST_IDS.map(str::to_string);
+        let db = chipdb::machxo2::get_full_chipinfo(&fixture::chipdb(), &const_ids).unwrap();
+        let min: chipdb::machxo2::MinimizedChipDatabase = db.into();
+        let encoded = chipdb::encode_min_chipinfo(min).unwrap();
+        MachXO2Arch::new(chipdb::decode_min_chipinfo(&encoded).unwrap())
+    }
+
+    #[test]
+    fn names_pips_like_ecp5() {
+        let arch = arch();
+        let pip = arch
+            .find_pip_decal_by_name("X1/Y0;X-1/Y0/F0_SLICE->X0/Y0/A0_SLICE")
+            .unwrap();
+
+        assert_eq!(pip.id, "X1/Y0;X-1/Y0/F0_SLICE->X0/Y0/A0_SLICE");
+        let (src, dst) = arch.get_pip_wires(&pip.decal).unwrap();
+        assert_eq!(
+            (src.id.as_str(), dst.id.as_str()),
+            ("X0/Y0/F0_SLICE", "X1/Y0/A0_SLICE")
+        );
+
+        let ids = |decals: Vec<Decal>| decals.into_iter().map(|d| d.id).collect::<Vec<_>>();
+        assert_eq!(ids(arch.get_pips_downhill(&src.decal)), [pip.id.as_str()]);
+        assert_eq!(ids(arch.get_pips_uphill(&dst.decal)), [pip.id.as_str()]);
+        assert_eq!(ids(arch.get_group_decals()), ["X0/Y0/TILE", "X1/Y0/TILE"]);
+    }
+
+    #[test]
+    fn draws_wires_and_bels_with_the_ecp5_gfx() {
+        let arch = arch();
+        let bel = arch.bel_decal(0, 0, 0).unwrap();
+        let wire = arch.wire_decal(0, 0, 0).unwrap();
+
+        assert_eq!(
+            arch.wire_gfx(0, 0, 0).unwrap().1,
+            GfxTileWireId::TILE_WIRE_F0_SLICE
+        );
+        assert!(!arch.get_decal_graphics(&bel.decal).is_empty());
+        assert!(!arch.get_decal_graphics(&wire.decal).is_empty());
+
+        let pins = arch.get_wire_bel_pins(&wire.decal);
+        assert_eq!(
+            (pins[0].pin.as_str(), pins[0].direction.as_str()),
+            ("F0", "out")
+        );
+        assert_eq!(pins[0].bel.id, "X0/Y0/SLICEA");
+    }
+
+    #[test]
+    fn counts_pips_and_wires_per_tile() {
+        let arch = arch();
+        let regions = arch.get_routing_regions();
+        let wire = arch.wire_decal(1, 0, 0).unwrap();
+        let pip = arch.pip_decal(1, 0, 0).unwrap();
+
+        let counts: Vec<_> = regions.iter().map(|r| (r.pips, r.wires)).collect();
+        assert_eq!(counts, [(0, 1), (1, 1)]);
+        assert_eq!(arch.get_wire_region(&wire.decal), Some(1));
+        assert_eq!(arch.get_pip_region(&pip.decal), Some(1));
+        assert_eq!(arch.get_wire_region(&pip.decal), None);
+    }
+
+    #[test]
+    fn reads_timing_per_speed_grade() {
+        let arch = arch();
+        let pip = arch.get_tile_pip_decals(1, 0).remove(0);
+
+        assert_eq!(arch.get_speed_grades(), ["1", "2"]);
+        let delays: Vec<_> = arch
+            .get_decal_delays(&pip.decal)
+            .into_iter()
+            .map(|d| (d.speed, d.max))
+            .collect();
+        assert_eq!(delays, [("1".to_string(), 70), ("2".to_string(), 60)]);
+
+        assert_eq!(
+            arch.get_cell_delay("TRELLIS_SLICE", "A0", "F0", "1"),
+            Some(150)
+        );
+        assert_eq!(
+            arch.get_cell_setup("TRELLIS_SLICE", "M0", "CLK", "2"),
+            Some(16)
+        );
+        // Packed cells fall back to the first half of the slice
+        assert_eq!(
+            arch.get_cell_delay("TRELLIS_COMB", "A", "F", "2"),
+            Some(120)
+        );
+        assert_eq!(arch.get_cell_delay("TRELLIS_SLICE", "A0", "F0", "3"), None);
+    }
+}
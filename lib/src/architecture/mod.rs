@@ -1,7 +1,12 @@
 mod ecp5;
 mod ice40;
+mod machxo2;
+mod nexus;
+mod trellis;
 mod types;
 
 pub use ecp5::ECP5Arch;
 pub use ice40::ICE40Arch;
-pub use types::{Architecture, Wire, WireLocation};
+pub use machxo2::MachXO2Arch;
+pub use nexus::NexusArch;
+pub use types::{Architecture, BelPin, ClockWire, PackagePin, ResourceDelay, Wire, WireLocation};
@@ -0,0 +1,742 @@
+use rustc_hash::FxHashMap;
+
+use super::types::{
+    port_direction, Architecture, BelPin, BelSite, ClockNetwork, ClockWire, PackagePin,
+    ResourceDelay, RoutingRegion, Wire, WireLocation,
+};
+use crate::chipdb;
+use crate::decal;
+use crate::gfx;
+
+type DecalID = decal::NexusDecalID;
+type Decal = decal::DecalXY<DecalID>;
+
+// `PadSide` values of `PadInfoPOD::side`
+const PIO_LEFT: i16 = 0;
+const PIO_RIGHT: i16 = 1;
+const PIO_TOP: i16 = 2;
+
+pub struct NexusArch {
+    chipdb: chipdb::nexus::MinimizedChipDatabase,
+    /// Holding tile and index of every BEL by its `(x, y, z)`, as BELs can be placed outside
+    /// the tile that holds them.
+    bel_locs: FxHashMap<(i32, i32, i32), (i32, usize)>,
+}
+
+impl NexusArch {
+    pub fn new(chipdb: chipdb::nexus::MinimizedChipDatabase) -> Self {
+        let mut arch = NexusArch {
+            chipdb,
+            bel_locs: FxHashMap::default(),
+        };
+
+        let width = arch.width();
+        for tile in 0..width * arch.height() {
+            let (x, y) = (tile % width, tile / width);
+            let Some(loc) = arch.location(x, y) else {
+                continue;
+            };
+            let locs: Vec<_> = loc
+                .bels
+                .iter()
+                .enumerate()
+                .map(|(i, b)| ((x + b.rel_x as i32, y + b.rel_y as i32, b.z), (tile, i)))
+                .collect();
+            arch.bel_locs.extend(locs);
+        }
+
+        arch
+    }
+
+    fn width(&self) -> i32 {
+        self.chipdb.chip.width.into()
+    }
+
+    fn height(&self) -> i32 {
+        self.chipdb.chip.height.into()
+    }
+
+    fn id_name(&self, id: i32) -> &str {
+        self.chipdb
+            .id_strs
+            .get(id as usize)
+            .map_or("?", |s| s.as_str())
+    }
+
+    fn grid(&self, x: i32, y: i32) -> Option<&chipdb::nexus::MinimizedGridLocationPOD> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return None;
+        }
+        self.chipdb.chip.grid.get((y * self.width() + x) as usize)
+    }
+
+    fn location(&self, x: i32, y: i32) -> Option<&chipdb::nexus::MinimizedLocTypePOD> {
+        self.grid(x, y)
+            .and_then(|g| self.chipdb.loctypes.get(g.loc_type as usize))
+    }
+
+    /// Timing data of a cell type for a speed grade, from the first of its variants.
+    fn find_cell_timing(
+        &self,
+        cell_type: &str,
+        speed: &str,
+    ) -> Option<&chipdb::nexus::MinimizedCellTimingPOD> {
+        self.chipdb
+            .speed_grades
+            .iter()
+            .find(|g| g.name == speed)?
+            .cell_types
+            .iter()
+            .find(|t| self.id_name(t.cell_type) == cell_type)
+    }
+
+    /// Tile wires related to a tile wire through its neighbourhood, as `(x, y, index)`.
+    fn neighbours(&self, x: i32, y: i32, index: usize) -> Vec<(i32, i32, usize, u8)> {
+        let Some(neighbours) = self
+            .grid(x, y)
+            .and_then(|g| self.chipdb.nhtypes.get(g.neighbourhood_type as usize))
+            .and_then(|n| n.wire_neighbours.get(index))
+        else {
+            return vec![];
+        };
+
+        neighbours
+            .uphill
+            .iter()
+            .chain(&neighbours.downhill)
+            .map(|r| {
+                (
+                    x + r.rel_x as i32,
+                    y + r.rel_y as i32,
+                    r.wire_index as usize,
+                    r.arc_flags,
+                )
+            })
+            .collect()
+    }
+
+    /// The primary wire naming the node a tile wire belongs to, like nextpnr's
+    /// `canonical_wire()`.
+    fn canonical_wire(&self, x: i32, y: i32, index: usize) -> Option<(i32, i32, usize)> {
+        let wire = self.location(x, y)?.wires.get(index)?;
+        if wire.flags & chipdb::nexus::WIRE_PRIMARY != 0 {
+            return Some((x, y, index));
+        }
+
+        self.neighbours(x, y, index)
+            .into_iter()
+            .find(|n| n.3 & chipdb::nexus::LOGICAL_TO_PRIMARY != 0)
+            .map(|(x, y, index, _)| (x, y, index))
+    }
+
+    /// All tile wires of the node named by a primary wire, starting with the primary wire.
+    fn wire_aliases(&self, x: i32, y: i32, index: usize) -> Vec<(i32, i32, usize)> {
+        let mut aliases = vec![(x, y, index)];
+        for (nx, ny, ni, _) in self.neighbours(x, y, index) {
+            if self.canonical_wire(nx, ny, ni) == Some((x, y, index))
+                && !aliases.contains(&(nx, ny, ni))
+            {
+                aliases.push((nx, ny, ni));
+            }
+        }
+        aliases
+    }
+
+    fn bel_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let bel = self.location(x, y)?.bels.get(index)?;
+        Some(Decal::new(
+            DecalID::new(
+                decal::NexusDecalType::TYPE_BEL,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!("X{x}/Y{y}/{}", self.id_name(bel.name)),
+        ))
+    }
+
+    /// Decal of the node a tile wire belongs to, named after its primary wire.
+    fn wire_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let (x, y, index) = self.canonical_wire(x, y, index)?;
+        let wire = self.location(x, y)?.wires.get(index)?;
+        Some(Decal::new(
+            DecalID::new(
+                decal::NexusDecalType::TYPE_WIRE,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!("X{x}/Y{y}/{}", self.id_name(wire.name)),
+        ))
+    }
+
+    /// Pip decal named after its tile and the tile wires it joins, like nextpnr's
+    /// `getPipName()`.
+    fn pip_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let loc = self.location(x, y)?;
+        let pip = loc.pips.get(index)?;
+        let from = loc.wires.get(pip.from_wire as usize)?;
+        let to = loc.wires.get(pip.to_wire as usize)?;
+
+        Some(Decal::new(
+            DecalID::new(
+                decal::NexusDecalType::TYPE_PIP,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!(
+                "X{x}/Y{y}/PIP/{}/{}",
+                self.id_name(from.name),
+                self.id_name(to.name)
+            ),
+        ))
+    }
+
+    fn wire_pips(&self, wire: &DecalID, uphill: bool) -> Vec<Decal> {
+        if wire.r#type != decal::NexusDecalType::TYPE_WIRE {
+            return vec![];
+        }
+
+        let (x, y) = (wire.location.x as i32, wire.location.y as i32);
+        self.wire_aliases(x, y, wire.z as usize)
+            .into_iter()
+            .flat_map(|(wx, wy, index)| {
+                let pips = self
+                    .location(wx, wy)
+                    .and_then(|l| l.wires.get(index))
+                    .map(|w| if uphill { &w.pips_uh } else { &w.pips_dh });
+                pips.into_iter()
+                    .flatten()
+                    .filter_map(move |&p| self.pip_decal(wx, wy, p as usize))
+            })
+            .collect()
+    }
+
+    /// BEL pins of a BEL in the tile holding it, with the tile wire of each pin.
+    fn bel_ports(&self, x: i32, y: i32, index: usize) -> Vec<BelPin<DecalID>> {
+        let Some(bel) = self.location(x, y).and_then(|l| l.bels.get(index)) else {
+            return vec![];
+        };
+        let Some(bel_decal) = self.bel_decal(x, y, index) else {
+            return vec![];
+        };
+
+        bel.ports
+            .iter()
+            .filter_map(|p| {
+                Some(BelPin {
+                    bel: bel_decal.clone(),
+                    pin: self.id_name(p.port).to_string(),
+                    direction: port_direction(p.r#type.into()),
+                    wire: self.wire_decal(x, y, p.wire_index as usize)?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Architecture<DecalID> for NexusArch {
+    fn get_decal_graphics(&self, decal: &DecalID) -> Vec<gfx::GraphicElement> {
+        let (x, y) = (decal.location.x as i32, decal.location.y as i32);
+        let Some(loc) = self.location(x, y) else {
+            return vec![];
+        };
+
+        match decal.r#type {
+            decal::NexusDecalType::TYPE_BEL => {
+                let Some(bel) = loc.bels.get(decal.z as usize) else {
+                    return vec![];
+                };
+                // Drawn where the BEL is, which may not be its holding tile
+                let (bx, by) = (x + bel.rel_x as i32, y + bel.rel_y as i32);
+                let Some(grid) = self.grid(bx, by) else {
+                    return vec![];
+                };
+                let logic = grid.loc_flags & chipdb::nexus::LOC_LOGIC != 0 && (bx, by) == (x, y);
+
+                gfx::nexus::tile_bel(
+                    bx as f64,
+                    by as f64,
+                    bel.z,
+                    logic,
+                    decal.z as usize,
+                    loc.bels.len(),
+                    &gfx::Style::Inactive,
+                )
+            }
+            decal::NexusDecalType::TYPE_WIRE => gfx::nexus::tile_wire(
+                decal.location.x,
+                decal.location.y,
+                decal.z as usize,
+                loc.wires.len(),
+                &gfx::Style::Inactive,
+            ),
+            decal::NexusDecalType::TYPE_PIP => {
+                let Some(pip) = loc.pips.get(decal.z as usize) else {
+                    return vec![];
+                };
+                gfx::nexus::tile_pip(
+                    decal.location.x,
+                    decal.location.y,
+                    pip.from_wire as usize,
+                    pip.to_wire as usize,
+                    loc.wires.len(),
+                    &gfx::Style::Hidden,
+                )
+            }
+            decal::NexusDecalType::TYPE_GROUP => {
+                gfx::tile_frame(decal.location.x, decal.location.y)
+            }
+            decal::NexusDecalType::TYPE_NONE => vec![],
+        }
+    }
+
+    fn get_bel_decals(&self) -> Vec<Decal> {
+        let width = self.width();
+        (0..width * self.height())
+            .flat_map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let len = self.location(x, y).map_or(0, |l| l.bels.len());
+                (0..len).filter_map(move |index| self.bel_decal(x, y, index))
+            })
+            .collect()
+    }
+
+    fn get_wire_decals(&self) -> Vec<Decal> {
+        // One decal per node, at its primary wire
+        let width = self.width();
+        (0..width * self.height())
+            .flat_map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let wires = self.location(x, y).map_or(&[][..], |l| &l.wires[..]);
+                wires
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, w)| w.flags & chipdb::nexus::WIRE_PRIMARY != 0)
+                    .filter_map(move |(index, _)| self.wire_decal(x, y, index))
+            })
+            .collect()
+    }
+
+    fn get_pip_decals(&self) -> Box<dyn Iterator<Item = Decal> + '_> {
+        let width = self.width();
+        Box::new((0..width * self.height()).flat_map(move |tile| {
+            let (x, y) = (tile % width, tile / width);
+            let len = self.location(x, y).map_or(0, |l| l.pips.len());
+            (0..len).filter_map(move |index| self.pip_decal(x, y, index))
+        }))
+    }
+
+    fn get_tile_pip_decals(&self, x: i32, y: i32) -> Vec<Decal> {
+        let len = self.location(x, y).map_or(0, |l| l.pips.len());
+        (0..len)
+            .filter_map(|index| self.pip_decal(x, y, index))
+            .collect()
+    }
+
+    fn get_group_decals(&self) -> Vec<Decal> {
+        let mut ret = vec![];
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let Some(loc) = self.location(x, y) else {
+                    continue;
+                };
+                if loc.bels.is_empty() && loc.pips.is_empty() {
+                    continue;
+                }
+                ret.push(Decal::new(
+                    DecalID::new(decal::NexusDecalType::TYPE_GROUP, x as f64, y as f64, 0.0),
+                    0.0,
+                    0.0,
+                    format!("X{x}/Y{y}/TILE"),
+                ));
+            }
+        }
+        ret
+    }
+
+    fn get_bel_site(&self, bel: &DecalID) -> Option<BelSite> {
+        if bel.r#type != decal::NexusDecalType::TYPE_BEL {
+            return None;
+        }
+
+        let (x, y) = (bel.location.x as i32, bel.location.y as i32);
+        let bel_info = self.location(x, y)?.bels.get(bel.z as usize)?;
+        Some(BelSite {
+            x: x + bel_info.rel_x as i32,
+            y: y + bel_info.rel_y as i32,
+            r#type: self.id_name(bel_info.r#type).to_string(),
+        })
+    }
+
+    fn get_routing_regions(&self) -> Vec<RoutingRegion> {
+        // One region per tile, in the same order as the grid
+        let width = self.width();
+        (0..width * self.height())
+            .map(|tile| {
+                let (x, y) = (tile % width, tile / width);
+                let (wires, pips) = self
+                    .location(x, y)
+                    .map_or((0, 0), |l| (l.wires.len(), l.pips.len()));
+
+                RoutingRegion {
+                    name: format!("X{x}/Y{y}"),
+                    x1: x as f64,
+                    y1: y as f64,
+                    x2: (x + 1) as f64,
+                    y2: (y + 1) as f64,
+                    pips: pips as u32,
+                    wires: wires as u32,
+                }
+            })
+            .collect()
+    }
+
+    fn get_pip_region(&self, pip: &DecalID) -> Option<usize> {
+        if pip.r#type != decal::NexusDecalType::TYPE_PIP {
+            return None;
+        }
+
+        Some((pip.location.y as i32 * self.width() + pip.location.x as i32) as usize)
+    }
+
+    fn get_wire_region(&self, wire: &DecalID) -> Option<usize> {
+        if wire.r#type != decal::NexusDecalType::TYPE_WIRE {
+            return None;
+        }
+
+        Some((wire.location.y as i32 * self.width() + wire.location.x as i32) as usize)
+    }
+
+    fn get_clock_network(&self) -> ClockNetwork {
+        // The global clock routing isn't kept in the minimized chipdb
+        ClockNetwork::default()
+    }
+
+    fn is_global_buffer(&self, _cell_type: &str) -> bool {
+        false
+    }
+
+    fn get_clock_wire(&self, _wire: &str) -> Option<ClockWire> {
+        None
+    }
+
+    fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        let Some(grid) = self.grid(x, y) else {
+            return vec![];
+        };
+
+        grid.phys_tiles
+            .iter()
+            .map(|t| {
+                format!(
+                    "{}R{y}C{x}:{}",
+                    self.id_name(t.prefix),
+                    self.id_name(t.tiletype)
+                )
+            })
+            .collect()
+    }
+
+    fn get_decal_tiles(&self, decal: &DecalID) -> Vec<String> {
+        self.get_tile_names(decal.location.x as i32, decal.location.y as i32)
+    }
+
+    fn get_speed_grades(&self) -> Vec<String> {
+        self.chipdb
+            .speed_grades
+            .iter()
+            .map(|g| g.name.clone())
+            .collect()
+    }
+
+    fn get_decal_delays(&self, decal: &DecalID) -> Vec<ResourceDelay> {
+        // Only pips have timing classes, wire delays are part of the pip delays
+        if decal.r#type != decal::NexusDecalType::TYPE_PIP {
+            return vec![];
+        }
+
+        let Some(pip) = self
+            .location(decal.location.x as i32, decal.location.y as i32)
+            .and_then(|l| l.pips.get(decal.z as usize))
+        else {
+            return vec![];
+        };
+
+        self.chipdb
+            .speed_grades
+            .iter()
+            .filter_map(|grade| {
+                let class = grade.pip_classes.get(pip.timing_class as usize)?;
+                Some(ResourceDelay {
+                    speed: grade.name.clone(),
+                    min: class.min_delay,
+                    max: class.max_delay,
+                    fanout_adder: class.max_fanout_adder,
+                })
+            })
+            .collect()
+    }
+
+    fn get_cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.find_cell_timing(cell_type, speed)?
+            .prop_delays
+            .iter()
+            .find(|d| self.id_name(d.from_port) == from_port && self.id_name(d.to_port) == to_port)
+            .map(|d| d.max_delay)
+    }
+
+    fn get_cell_setup(
+        &self,
+        cell_type: &str,
+        port: &str,
+        clock_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        self.find_cell_timing(cell_type, speed)?
+            .setup_holds
+            .iter()
+            .find(|s| self.id_name(s.sig_port) == port && self.id_name(s.clock_port) == clock_port)
+            .map(|s| s.max_setup)
+    }
+
+    fn get_packages(&self) -> Vec<String> {
+        self.chipdb
+            .chip
+            .packages
+            .iter()
+            .map(|p| p.short_name.clone())
+            .collect()
+    }
+
+    fn get_package_pins(&self, package: &str) -> Vec<PackagePin> {
+        let Some(package_index) = self
+            .chipdb
+            .chip
+            .packages
+            .iter()
+            .position(|p| p.short_name == package)
+        else {
+            return vec![];
+        };
+
+        self.chipdb
+            .chip
+            .pads
+            .iter()
+            .filter_map(|pad| {
+                let name = pad.pins.get(package_index).filter(|n| !n.is_empty())?;
+                let offset = pad.offset as i32;
+                let (x, y) = match pad.side {
+                    PIO_LEFT => (0, offset),
+                    PIO_RIGHT => (self.width() - 1, offset),
+                    PIO_TOP => (offset, 0),
+                    _ => (offset, self.height() - 1),
+                };
+                let &(tile, index) = self.bel_locs.get(&(x, y, pad.pio_index as i32))?;
+                let bel = self.bel_decal(tile % self.width(), tile / self.width(), index)?;
+
+                let functions: Vec<&str> = (pad.func >= 0)
+                    .then_some(pad.func as i32)
+                    .into_iter()
+                    .chain(pad.extra_funcs.iter().map(|&f| f as i32))
+                    .map(|f| self.id_name(f))
+                    .collect();
+
+                Some(PackagePin {
+                    name: name.clone(),
+                    bel: bel.id,
+                    function: (!functions.is_empty()).then(|| functions.join("/")),
+                    bank: Some(pad.bank.into()),
+                    dqs_group: None,
+                })
+            })
+            .collect()
+    }
+
+    fn get_bel_pins(&self, bel: &DecalID) -> Vec<BelPin<DecalID>> {
+        if bel.r#type != decal::NexusDecalType::TYPE_BEL {
+            return vec![];
+        }
+
+        self.bel_ports(bel.location.x as i32, bel.location.y as i32, bel.z as usize)
+    }
+
+    fn get_wire_bel_pins(&self, wire: &DecalID) -> Vec<BelPin<DecalID>> {
+        if wire.r#type != decal::NexusDecalType::TYPE_WIRE {
+            return vec![];
+        }
+
+        let (x, y) = (wire.location.x as i32, wire.location.y as i32);
+        self.wire_aliases(x, y, wire.z as usize)
+            .into_iter()
+            .flat_map(|(wx, wy, index)| {
+                let bel_pins = self
+                    .location(wx, wy)
+                    .and_then(|l| l.wires.get(index))
+                    .map_or(&[][..], |w| &w.bel_pins[..]);
+                bel_pins.iter().filter_map(move |p| {
+                    self.bel_ports(wx, wy, p.bel as usize)
+                        .into_iter()
+                        .find(|b| b.pin == self.id_name(p.pin))
+                })
+            })
+            .collect()
+    }
+
+    fn get_pips_uphill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, true)
+    }
+
+    fn get_pips_downhill(&self, wire: &DecalID) -> Vec<Decal> {
+        self.wire_pips(wire, false)
+    }
+
+    fn get_pip_src_node(&self, pip: &DecalID) -> Option<String> {
+        // The pip's tile wire may be any wire of the node, the node is named by its primary wire
+        self.get_pip_wires(pip).map(|(src, _)| src.id)
+    }
+
+    fn get_pip_wires(&self, pip: &DecalID) -> Option<(Decal, Decal)> {
+        if pip.r#type != decal::NexusDecalType::TYPE_PIP {
+            return None;
+        }
+
+        let (x, y) = (pip.location.x as i32, pip.location.y as i32);
+        let pip_data = self.location(x, y)?.pips.get(pip.z as usize)?;
+        Some((
+            self.wire_decal(x, y, pip_data.from_wire as usize)?,
+            self.wire_decal(x, y, pip_data.to_wire as usize)?,
+        ))
+    }
+
+    fn find_pip_decal_by_loc_from_to(
+        &self,
+        location: &WireLocation,
+        from: &Wire,
+        to: &Wire,
+    ) -> Option<Decal> {
+        let (x, y) = (location.x as i32, location.y as i32);
+        let loc = self.location(x, y)?;
+        let wire_name = |index: u16| {
+            loc.wires
+                .get(index as usize)
+                .map_or("", |w| self.id_name(w.name))
+        };
+
+        let index = loc
+            .pips
+            .iter()
+            .position(|p| wire_name(p.from_wire) == from.name && wire_name(p.to_wire) == to.name)?;
+        self.pip_decal(x, y, index)
+    }
+
+    fn find_pip_decal_by_name(&self, name: &str) -> Option<Decal> {
+        // `X{x}/Y{y}/PIP/{from}/{to}`, both tile wires of the pip's tile
+        let (location, wires) = name.split_once("/PIP/")?;
+        let (x, y) = location.split_once('/')?;
+        let (from, to) = wires.split_once('/')?;
+        let tile_wire = |name: &str| Wire {
+            location: WireLocation { x: 0, y: 0 },
+            name: name.to_string(),
+        };
+
+        let location = WireLocation {
+            x: x.strip_prefix('X')?.parse().ok()?,
+            y: y.strip_prefix('Y')?.parse().ok()?,
+        };
+        self.find_pip_decal_by_loc_from_to(&location, &tile_wire(from), &tile_wire(to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chipdb::nexus::fixture;
+
+    /// The fixture chipdb, minimized and loaded like the viewer does.
+    fn arch() -> NexusArch {
+        let const_ids = fixture::CONST_IDS.map(str::to_string);
+        let db =
+            chipdb::nexus::get_full_chipinfo(&fixture::chipdb(), Some("LIFCL-TEST"), &const_ids)
+                .unwrap();
+        let min: chipdb::nexus::MinimizedChipDatabase = db.into();
+        let encoded = chipdb::encode_min_chipinfo(min).unwrap();
+        NexusArch::new(chipdb::decode_min_chipinfo(&encoded).unwrap())
+    }
+
+    #[test]
+    fn resolves_pip_sources_to_their_node() {
+        let arch = arch();
+        let pip = arch.find_pip_decal_by_name("X1/Y0/PIP/B/A").unwrap();
+
+        assert_eq!(pip.id, "X1/Y0/PIP/B/A");
+        assert_eq!(
+            arch.get_pip_src_node(&pip.decal).as_deref(),
+            Some("X0/Y0/A")
+        );
+        let (src, dst) = arch.get_pip_wires(&pip.decal).unwrap();
+        assert_eq!((src.id.as_str(), dst.id.as_str()), ("X0/Y0/A", "X1/Y0/A"));
+        assert!(arch.find_pip_decal_by_name("X1/Y0/PIP/A/B").is_none());
+    }
+
+    #[test]
+    fn walks_the_pips_of_a_whole_node() {
+        let arch = arch();
+        let wire = arch.wire_decal(0, 0, 0).unwrap();
+
+        let downhill: Vec<_> = arch
+            .get_pips_downhill(&wire.decal)
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(downhill, ["X1/Y0/PIP/B/A"]);
+        // Only primary wires get a decal of their own
+        assert_eq!(arch.get_wire_decals().len(), 2);
+    }
+
+    #[test]
+    fn reads_timing_per_speed_grade() {
+        let arch = arch();
+        let pip = arch.find_pip_decal_by_name("X0/Y0/PIP/B/A").unwrap();
+
+        assert_eq!(arch.get_speed_grades(), ["10", "12"]);
+        let delays: Vec<_> = arch
+            .get_decal_delays(&pip.decal)
+            .into_iter()
+            .map(|d| (d.speed, d.max, d.fanout_adder))
+            .collect();
+        assert_eq!(
+            delays,
+            [("10".to_string(), 70, 5), ("12".to_string(), 60, 4)]
+        );
+        assert_eq!(arch.get_cell_delay("LUT4", "A0", "F", "12"), Some(120));
+        assert_eq!(arch.get_cell_delay("LUT4", "A0", "F", "9"), None);
+    }
+
+    #[test]
+    fn finds_package_pins_and_tiles() {
+        let arch = arch();
+
+        let pins = arch.get_package_pins("QFN72");
+        assert_eq!(pins.len(), 1);
+        assert_eq!(
+            (pins[0].name.as_str(), pins[0].bel.as_str()),
+            ("A1", "X0/Y0/SLICEA")
+        );
+        assert_eq!(arch.get_tile_names(1, 0), ["R0C1:PLC"]);
+    }
+}
@@ -0,0 +1,362 @@
+use super::types::ResourceDelay;
+use crate::chipdb;
+use crate::decal;
+use crate::gfx;
+use crate::gfx::ecp5::{consts, ConstId, GfxTileWireId};
+
+type DecalID = decal::ECP5DecalID;
+type Decal = decal::DecalXY<DecalID>;
+
+// Ports shared by both halves of a slice, all others are numbered per half
+const SLICE_SHARED_PORTS: [&str; 7] = ["CLK", "LSR", "CE", "FCI", "FCO", "WCK", "WRE"];
+
+/// Source and destination wires of a pip, located relative to the pip's tile.
+pub(super) struct TrellisPip {
+    pub src: (i32, i32, usize),
+    pub dst: (i32, i32, usize),
+    pub timing_class: usize,
+}
+
+/// The chipdbs built from the Trellis database, ECP5 and MachXO2, lay out their tiles and
+/// timing the same way. Each implements the lookups into its own tiles, and gets the decals,
+/// their graphics and the timing queries from the provided methods.
+pub(super) trait Trellis {
+    /// Names of the speed grades in the chipdb's `speed_grades`, in order.
+    const SPEED_GRADE_NAMES: &'static [&'static str];
+
+    fn width(&self) -> i32;
+    fn height(&self) -> i32;
+    /// Numbers of BELs, wires and pips of the tile at `x`, `y`, zeros outside the grid.
+    fn tile_len(&self, x: i32, y: i32) -> [usize; 3];
+    fn bel_name(&self, x: i32, y: i32, index: usize) -> Option<&str>;
+    /// ECP5 type and z of a BEL, for its graphics.
+    fn bel_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, i32)>;
+    fn wire_name(&self, x: i32, y: i32, index: usize) -> Option<&str>;
+    /// ECP5 wire type and tile wire of a wire, for its graphics.
+    fn wire_gfx(&self, x: i32, y: i32, index: usize) -> Option<(ConstId, GfxTileWireId)>;
+    fn wire_pip_locators(
+        &self,
+        x: i32,
+        y: i32,
+        index: usize,
+        uphill: bool,
+    ) -> Option<&[chipdb::ecp5::MinimizedPipLocatorPOD]>;
+    fn pip(&self, x: i32, y: i32, index: usize) -> Option<TrellisPip>;
+    fn speed_grades(&self) -> &[chipdb::ecp5::MinimizedSpeedGradePOD];
+    fn id_name(&self, id: i32) -> String;
+
+    fn bel_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let name = self.bel_name(x, y, index)?;
+        Some(Decal::new(
+            DecalID::new(
+                decal::ECP5DecalType::TYPE_BEL,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!("X{x}/Y{y}/{name}"),
+        ))
+    }
+
+    fn wire_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let name = self.wire_name(x, y, index)?;
+        Some(Decal::new(
+            DecalID::new(
+                decal::ECP5DecalType::TYPE_WIRE,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!("X{x}/Y{y}/{name}"),
+        ))
+    }
+
+    /// Pip decal named after its tile and its source and destination wires, both located
+    /// relative to the pip's tile.
+    fn pip_decal(&self, x: i32, y: i32, index: usize) -> Option<Decal> {
+        let TrellisPip { src, dst, .. } = self.pip(x, y, index)?;
+        let src_name = self.wire_name(x + src.0, y + src.1, src.2)?;
+        let dst_name = self.wire_name(x + dst.0, y + dst.1, dst.2)?;
+
+        Some(Decal::new(
+            DecalID::new(
+                decal::ECP5DecalType::TYPE_PIP,
+                x as f64,
+                y as f64,
+                index as f64,
+            ),
+            0.0,
+            0.0,
+            format!(
+                "X{x}/Y{y};X{}/Y{}/{src_name}->X{}/Y{}/{dst_name}",
+                src.0, src.1, dst.0, dst.1
+            ),
+        ))
+    }
+
+    fn wire_pips(&self, wire: &DecalID, uphill: bool) -> Vec<Decal> {
+        if wire.r#type != decal::ECP5DecalType::TYPE_WIRE {
+            return vec![];
+        }
+
+        let (x, y) = (wire.location.x as i32, wire.location.y as i32);
+        let Some(pips) = self.wire_pip_locators(x, y, wire.z as usize, uphill) else {
+            return vec![];
+        };
+
+        pips.iter()
+            .filter_map(|p| {
+                let (px, py) = (x + p.rel_loc.x as i32, y + p.rel_loc.y as i32);
+                self.pip_decal(px, py, p.index as usize)
+            })
+            .collect()
+    }
+
+    /// Source and destination wires of a pip decal.
+    fn pip_wire_decals(&self, pip: &DecalID) -> Option<(Decal, Decal)> {
+        if pip.r#type != decal::ECP5DecalType::TYPE_PIP {
+            return None;
+        }
+
+        let (x, y) = (pip.location.x as i32, pip.location.y as i32);
+        let TrellisPip { src, dst, .. } = self.pip(x, y, pip.z as usize)?;
+        Some((
+            self.wire_decal(x + src.0, y + src.1, src.2)?,
+            self.wire_decal(x + dst.0, y + dst.1, dst.2)?,
+        ))
+    }
+
+    fn tile_pip_decals(&self, x: i32, y: i32) -> Vec<Decal> {
+        let [_, _, pips] = self.tile_len(x, y);
+        (0..pips)
+            .filter_map(|index| self.pip_decal(x, y, index))
+            .collect()
+    }
+
+    /// A frame around every tile with BELs or pips, and a switchbox in the interior ones
+    /// with pips.
+    fn group_decals(&self) -> Vec<Decal> {
+        let mut ret: Vec<Decal> = vec![];
+        let mut push = |x: i32, y: i32, r#type: decal::ECP5GroupType| {
+            ret.push(Decal::new(
+                DecalID::new(
+                    decal::ECP5DecalType::TYPE_GROUP,
+                    x as f64,
+                    y as f64,
+                    r#type as i8 as f64,
+                ),
+                0.0,
+                0.0,
+                format!("X{x}/Y{y}/{}", r#type.name()),
+            ))
+        };
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let [bels, _, pips] = self.tile_len(x, y);
+                if bels == 0 && pips == 0 {
+                    continue;
+                }
+                push(x, y, decal::ECP5GroupType::TYPE_FRAME);
+
+                // Like nextpnr, only the interior tiles have a switchbox
+                let interior =
+                    (1..self.width() - 1).contains(&x) && (1..self.height() - 1).contains(&y);
+                if interior && pips > 0 {
+                    push(x, y, decal::ECP5GroupType::TYPE_SWITCHBOX);
+                }
+            }
+        }
+
+        ret
+    }
+
+    fn decal_graphics(&self, decal: &DecalID) -> Vec<gfx::GraphicElement> {
+        let (x, y) = (decal.location.x, decal.location.y);
+        let (tx, ty, index) = (x as i32, y as i32, decal.z as usize);
+        let (width, height) = (self.width(), self.height());
+
+        if decal.r#type == decal::ECP5DecalType::TYPE_BEL {
+            let Some((r#type, z)) = self.bel_gfx(tx, ty, index) else {
+                return vec![];
+            };
+
+            let style = &gfx::Style::Inactive;
+            let g = gfx::ecp5::tile_bel(x, y, z, width, height, &r#type, style);
+            if !g.is_empty() {
+                return g;
+            }
+
+            // Stacked by z like the other miscellaneous BELs
+            let z = z as f64;
+            return gfx::unknown_bel(
+                x + consts::slice_x1,
+                y + consts::slice_y1 + z * consts::slice_pitch,
+                x + consts::slice_x2_wide,
+                y + consts::slice_y2 + z * consts::slice_pitch,
+                *style,
+            );
+        } else if decal.r#type == decal::ECP5DecalType::TYPE_WIRE {
+            let Some((wire_type, tile_wire)) = self.wire_gfx(tx, ty, index) else {
+                return vec![];
+            };
+            let style = &gfx::Style::Inactive;
+            return gfx::ecp5::tile_wire(x, y, width, height, &wire_type, &tile_wire, style);
+        } else if decal.r#type == decal::ECP5DecalType::TYPE_GROUP {
+            let r#type = decal::ECP5GroupType::try_from(decal.z as i8)
+                .unwrap_or(decal::ECP5GroupType::TYPE_NONE);
+
+            if r#type == decal::ECP5GroupType::TYPE_SWITCHBOX {
+                let mut el = gfx::GraphicElement::new(gfx::Type::Box, gfx::Style::Frame);
+                el.x1 = x + consts::switchbox_x1;
+                el.x2 = x + consts::switchbox_x2;
+                el.y1 = y + consts::switchbox_y1;
+                el.y2 = y + consts::switchbox_y2;
+                return vec![el];
+            }
+
+            if r#type == decal::ECP5GroupType::TYPE_FRAME {
+                return gfx::tile_frame(x, y);
+            }
+        } else if decal.r#type == decal::ECP5DecalType::TYPE_PIP {
+            let Some(TrellisPip { src, dst, .. }) = self.pip(tx, ty, index) else {
+                return vec![];
+            };
+            let Some((src_type, src_id)) = self.wire_gfx(tx + src.0, ty + src.1, src.2) else {
+                return vec![];
+            };
+            let Some((dst_type, dst_id)) = self.wire_gfx(tx + dst.0, ty + dst.1, dst.2) else {
+                return vec![];
+            };
+
+            let wire_id = |(dx, dy, _): (i32, i32, usize)| gfx::ecp5::WireId {
+                location: gfx::ecp5::Location {
+                    x: x + dx as f64,
+                    y: y + dy as f64,
+                },
+            };
+            return gfx::ecp5::tile_pip(
+                x,
+                y,
+                width,
+                height,
+                &wire_id(src),
+                &src_type,
+                &src_id,
+                &wire_id(dst),
+                &dst_type,
+                &dst_id,
+                &gfx::Style::Hidden,
+            );
+        }
+
+        vec![]
+    }
+
+    fn speed_grade_names(&self) -> Vec<String> {
+        Self::SPEED_GRADE_NAMES
+            .iter()
+            .take(self.speed_grades().len())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Delays of a pip's timing class in every speed grade. Wires have no delay of their own
+    /// in the Trellis timing model.
+    fn pip_delays(&self, decal: &DecalID) -> Vec<ResourceDelay> {
+        if decal.r#type != decal::ECP5DecalType::TYPE_PIP {
+            return vec![];
+        }
+
+        let (x, y) = (decal.location.x as i32, decal.location.y as i32);
+        let Some(pip) = self.pip(x, y, decal.z as usize) else {
+            return vec![];
+        };
+
+        self.speed_grade_names()
+            .into_iter()
+            .zip(self.speed_grades())
+            .filter_map(|(speed, grade)| {
+                let class = grade.pip_classes.get(pip.timing_class)?;
+                Some(ResourceDelay {
+                    speed,
+                    min: class.min_base_delay,
+                    max: class.max_base_delay,
+                    fanout_adder: class.max_fanout_adder,
+                })
+            })
+            .collect()
+    }
+
+    /// Timing data of a cell type for a speed grade. The packed `TRELLIS_COMB`, `TRELLIS_FF`
+    /// and `TRELLIS_RAMW` cells fall back to the first half of `TRELLIS_SLICE`, with their
+    /// ports renamed accordingly.
+    fn find_cell_timing(
+        &self,
+        cell_type: &str,
+        ports: [&str; 2],
+        speed: &str,
+    ) -> Option<(&chipdb::ecp5::MinimizedCellTimingPOD, [String; 2])> {
+        let grade = Self::SPEED_GRADE_NAMES
+            .iter()
+            .position(|&s| s == speed)
+            .and_then(|i| self.speed_grades().get(i))?;
+        let find = |cell_type: &str| {
+            grade
+                .cell_timings
+                .iter()
+                .find(|t| self.id_name(t.cell_type) == cell_type)
+        };
+
+        if let Some(timing) = find(cell_type) {
+            return Some((timing, ports.map(str::to_string)));
+        }
+
+        if !matches!(cell_type, "TRELLIS_COMB" | "TRELLIS_FF" | "TRELLIS_RAMW") {
+            return None;
+        }
+        let ports = ports.map(|p| {
+            if SLICE_SHARED_PORTS.contains(&p) {
+                p.to_string()
+            } else {
+                format!("{p}0")
+            }
+        });
+        Some((find("TRELLIS_SLICE")?, ports))
+    }
+
+    fn cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        let (timing, [from, to]) = self.find_cell_timing(cell_type, [from_port, to_port], speed)?;
+        timing
+            .prop_delays
+            .iter()
+            .find(|d| self.id_name(d.from_port) == from && self.id_name(d.to_port) == to)
+            .map(|d| d.max_delay)
+    }
+
+    fn cell_setup(
+        &self,
+        cell_type: &str,
+        port: &str,
+        clock_port: &str,
+        speed: &str,
+    ) -> Option<i32> {
+        let (timing, [port, clock]) =
+            self.find_cell_timing(cell_type, [port, clock_port], speed)?;
+        timing
+            .setup_holds
+            .iter()
+            .find(|s| self.id_name(s.sig_port) == port && self.id_name(s.clock_port) == clock)
+            .map(|s| s.max_setup)
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::decal;
 use crate::gfx;
 
@@ -13,17 +15,197 @@ pub struct Wire {
     pub name: String,
 }
 
+/// An area of the chip (a tile or a switchbox) whose routing usage is aggregated for the
+/// congestion heatmap.
+#[derive(Clone)]
+pub struct RoutingRegion {
+    pub name: String,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    /// Number of pips available in the region.
+    pub pips: u32,
+    /// Number of wires available in the region, zero if wires are not counted per region.
+    pub wires: u32,
+}
+
+/// Tile and type of a BEL, used to aggregate placement utilization.
+pub struct BelSite {
+    pub x: i32,
+    pub y: i32,
+    pub r#type: String,
+}
+
+/// Intrinsic delay of a pip or wire for one speed grade, in picoseconds.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ResourceDelay {
+    pub speed: String,
+    pub min: i32,
+    pub max: i32,
+    /// Added to `max` for every other pip used downhill of the same source wire.
+    pub fanout_adder: i32,
+}
+
+/// A bonded pin of a package and the IO BEL behind it.
+pub struct PackagePin {
+    /// Ball or pin name, e.g. `B11` or `45`.
+    pub name: String,
+    pub bel: String,
+    /// PIO function name, e.g. `PL23A` or `PB4A/D7`.
+    pub function: Option<String>,
+    pub bank: Option<i32>,
+    pub dqs_group: Option<i32>,
+}
+
+/// A pin of a BEL and the wire it is attached to.
+pub struct BelPin<DecalID> {
+    pub bel: decal::DecalXY<DecalID>,
+    pub pin: String,
+    /// `in`, `out` or `inout`.
+    pub direction: String,
+    pub wire: decal::DecalXY<DecalID>,
+}
+
+/// Direction of a nextpnr `PortType` value.
+pub(super) fn port_direction(port_type: i32) -> String {
+    match port_type {
+        0 => "in",
+        1 => "out",
+        _ => "inout",
+    }
+    .to_string()
+}
+
+/// Splits a `X<x>/Y<y>;<src wire>-><dst wire>` pip name, with `X<x>/Y<y>/<name>` wires, into
+/// the pip location and its wires, see `Architecture::find_pip_decal_by_loc_from_to`.
+pub(super) fn parse_pip_name(pip: &str) -> Option<(WireLocation, Wire, Wire)> {
+    let (location, wires) = pip.split_once(';')?;
+    let (src, dst) = wires.split_once("->")?;
+
+    fn split(name: &str) -> Option<(WireLocation, Option<&str>)> {
+        let mut parts = name.splitn(3, '/');
+        let x = parts.next()?.strip_prefix('X')?.parse().ok()?;
+        let y = parts.next()?.strip_prefix('Y')?.parse().ok()?;
+        Some((WireLocation { x, y }, parts.next()))
+    }
+    let wire = |name: &str| {
+        let (location, name) = split(name)?;
+        Some(Wire {
+            location,
+            name: name?.to_string(),
+        })
+    };
+
+    Some((split(location)?.0, wire(src)?, wire(dst)?))
+}
+
+/// A quadrant of the global clock network.
+pub struct ClockQuadrant {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+}
+
+/// The tile where a quadrant's global clocks enter a spine.
+pub struct ClockSpine {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A column tap feeding the horizontal clock branches of rows `y1..=y2` on one side.
+pub struct ClockTap {
+    pub x: i32,
+    pub y1: i32,
+    pub y2: i32,
+    pub left: bool,
+}
+
+/// Part of the global clock network a routed wire runs on.
+pub enum ClockWire {
+    /// The spine entering the tile at `x`, `y`.
+    Spine { x: i32, y: i32 },
+    /// A horizontal branch at `x`, `y`, fed by the tap on its left or right.
+    Tap { x: i32, y: i32, left: bool },
+}
+
+/// Topology of the dedicated global clock routing, empty if the architecture has none.
+#[derive(Default)]
+pub struct ClockNetwork {
+    pub quadrants: Vec<ClockQuadrant>,
+    pub spines: Vec<ClockSpine>,
+    pub taps: Vec<ClockTap>,
+}
+
 pub trait Architecture<DecalID> {
     fn get_decal_graphics(&self, decal: &DecalID) -> Vec<gfx::GraphicElement>;
     fn get_bel_decals(&self) -> Vec<decal::DecalXY<DecalID>>;
     fn get_wire_decals(&self) -> Vec<decal::DecalXY<DecalID>>;
-    #[allow(dead_code)]
-    fn get_pip_decals(&self) -> Vec<decal::DecalXY<DecalID>>;
+    /// Every pip of the device, generated lazily as large parts have millions of them.
+    fn get_pip_decals(&self) -> Box<dyn Iterator<Item = decal::DecalXY<DecalID>> + '_>;
+    /// Pips drawn in the tile at a grid location.
+    fn get_tile_pip_decals(&self, x: i32, y: i32) -> Vec<decal::DecalXY<DecalID>>;
     fn get_group_decals(&self) -> Vec<decal::DecalXY<DecalID>>;
+    fn get_bel_site(&self, bel: &DecalID) -> Option<BelSite>;
+    fn get_routing_regions(&self) -> Vec<RoutingRegion>;
+    /// Index into `get_routing_regions` of the region a pip decal belongs to.
+    fn get_pip_region(&self, pip: &DecalID) -> Option<usize>;
+    /// Index into `get_routing_regions` of the region a wire decal belongs to.
+    fn get_wire_region(&self, wire: &DecalID) -> Option<usize>;
+    fn get_clock_network(&self) -> ClockNetwork;
+    /// Whether cells of a type drive the dedicated global clock routing.
+    fn is_global_buffer(&self, cell_type: &str) -> bool;
+    /// Spine or tap of `get_clock_network` a wire, named like the wire decals, runs on.
+    fn get_clock_wire(&self, wire: &str) -> Option<ClockWire>;
+    /// Bitstream tiles at a grid location as `name:type`, e.g. `R12C34:PLC2`.
+    fn get_tile_names(&self, x: i32, y: i32) -> Vec<String>;
+    fn get_decal_tiles(&self, decal: &DecalID) -> Vec<String>;
+    fn get_speed_grades(&self) -> Vec<String>;
+    /// Delay of a pip or wire decal for every speed grade, empty for other decals.
+    fn get_decal_delays(&self, decal: &DecalID) -> Vec<ResourceDelay>;
+    /// Maximum delay of a cell timing arc, in picoseconds.
+    fn get_cell_delay(
+        &self,
+        cell_type: &str,
+        from_port: &str,
+        to_port: &str,
+        speed: &str,
+    ) -> Option<i32>;
+    /// Maximum setup time of a cell input relative to its clock, in picoseconds.
+    fn get_cell_setup(
+        &self,
+        cell_type: &str,
+        port: &str,
+        clock_port: &str,
+        speed: &str,
+    ) -> Option<i32>;
+    fn get_packages(&self) -> Vec<String>;
+    fn get_package_pins(&self, package: &str) -> Vec<PackagePin>;
+    /// Pins of a BEL decal with the wires they are attached to.
+    fn get_bel_pins(&self, bel: &DecalID) -> Vec<BelPin<DecalID>>;
+    /// BEL pins a wire decal is attached to.
+    fn get_wire_bel_pins(&self, wire: &DecalID) -> Vec<BelPin<DecalID>>;
+    /// Pips driving a wire decal, like nextpnr's `getPipsUphill()`.
+    fn get_pips_uphill(&self, wire: &DecalID) -> Vec<decal::DecalXY<DecalID>>;
+    /// Pips driven by a wire decal, like nextpnr's `getPipsDownhill()`.
+    fn get_pips_downhill(&self, wire: &DecalID) -> Vec<decal::DecalXY<DecalID>>;
+    /// Name of the node driving a pip decal, like the wires of a net's `ROUTING`, where the
+    /// pip's source wire doesn't name it.
+    fn get_pip_src_node(&self, _pip: &DecalID) -> Option<String> {
+        None
+    }
+    /// Source and destination wire decals of a pip decal.
+    fn get_pip_wires(
+        &self,
+        pip: &DecalID,
+    ) -> Option<(decal::DecalXY<DecalID>, decal::DecalXY<DecalID>)>;
     fn find_pip_decal_by_loc_from_to(
         &self,
         location: &WireLocation,
         from: &Wire,
         to: &Wire,
     ) -> Option<decal::DecalXY<DecalID>>;
+    /// Pip decal named `name`, as listed by `get_pip_decals`.
+    fn find_pip_decal_by_name(&self, name: &str) -> Option<decal::DecalXY<DecalID>>;
 }
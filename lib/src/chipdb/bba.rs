@@ -0,0 +1,87 @@
+/// Writes chipdbs in the layout `bbasm` gives them, for parser tests. Blocks are written
+/// children first, so every relative pointer refers to data that is already placed.
+pub struct Bba {
+    data: Vec<u8>,
+}
+
+impl Default for Bba {
+    /// Starts a chipdb with room for the pointer to its root struct.
+    fn default() -> Self {
+        Self { data: vec![0; 4] }
+    }
+}
+
+impl Bba {
+    /// Offset the next value is written at.
+    pub fn pos(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    pub fn u8(&mut self, val: u8) -> &mut Self {
+        self.data.push(val);
+        self
+    }
+
+    pub fn i16(&mut self, val: i16) -> &mut Self {
+        self.data.extend(val.to_le_bytes());
+        self
+    }
+
+    pub fn u16(&mut self, val: u16) -> &mut Self {
+        self.data.extend(val.to_le_bytes());
+        self
+    }
+
+    pub fn i32(&mut self, val: i32) -> &mut Self {
+        self.data.extend(val.to_le_bytes());
+        self
+    }
+
+    pub fn u32(&mut self, val: u32) -> &mut Self {
+        self.data.extend(val.to_le_bytes());
+        self
+    }
+
+    /// `RelPtr` to the data at `target`.
+    pub fn ptr(&mut self, target: u32) -> &mut Self {
+        self.i32(target as i32 - self.pos() as i32)
+    }
+
+    /// `RelSlice` of `len` items starting at `target`.
+    pub fn slice(&mut self, target: u32, len: usize) -> &mut Self {
+        self.ptr(target).u32(len as u32)
+    }
+
+    /// Places a zero-terminated string and returns its offset.
+    pub fn string(&mut self, s: &str) -> u32 {
+        let at = self.pos();
+        self.data.extend(s.as_bytes());
+        self.data.push(0);
+        at
+    }
+
+    /// Places a `RelSlice<RelPtr<char>>` of strings and returns its offset.
+    pub fn strings(&mut self, strings: &[&str]) -> u32 {
+        let offsets: Vec<_> = strings.iter().map(|s| self.string(s)).collect();
+        let at = self.pos();
+        for offset in offsets {
+            self.ptr(offset);
+        }
+        at
+    }
+
+    /// Places `i32`s and returns their offset.
+    pub fn i32s(&mut self, vals: &[i32]) -> u32 {
+        let at = self.pos();
+        for &val in vals {
+            self.i32(val);
+        }
+        at
+    }
+
+    /// Points the chipdb at its root struct at `root`.
+    pub fn finish(mut self, root: u32) -> Vec<u8> {
+        self.data[..4].copy_from_slice(&root.to_le_bytes());
+        self.data
+    }
+}
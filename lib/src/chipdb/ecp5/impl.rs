@@ -54,7 +54,7 @@ impl POD for BelInfoPOD {
     fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
         Ok(Self {
             name: read_relstring(cur)?,
-            r#type: ConstId::try_from(cur.read_u32::<LittleEndian>()?)?,
+            r#type: ConstId::from(cur.read_u32::<LittleEndian>()?),
             z: cur.read_i32::<LittleEndian>()?,
             bel_wires: read_relslice::<BelWirePOD>(cur)?,
         })
@@ -108,8 +108,8 @@ impl POD for WireInfoPOD {
     fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
         Ok(Self {
             name: read_relstring(cur)?,
-            r#type: ConstId::try_from(cur.read_i16::<LittleEndian>()? as u32)?,
-            tile_wire: GfxTileWireId::try_from(cur.read_i16::<LittleEndian>()? as u32)?,
+            r#type: ConstId::from(cur.read_i16::<LittleEndian>()? as u32),
+            tile_wire: GfxTileWireId::from(cur.read_i16::<LittleEndian>()? as u32),
             pips_uphill: read_relslice::<PipLocatorPOD>(cur)?,
             pips_downhill: read_relslice::<PipLocatorPOD>(cur)?,
             bel_pins: read_relslice::<BelPortPOD>(cur)?,
@@ -2,4 +2,13 @@ mod r#impl;
 mod types;
 
 pub use r#impl::get_full_chipinfo;
-pub use types::MinimizedChipInfoPOD;
+pub use types::{
+    MinimizedCellTimingPOD, MinimizedChipInfoPOD, MinimizedLocationTypePOD, MinimizedPipLocatorPOD,
+    MinimizedSpeedGradePOD,
+};
+// Shared with the MachXO2 chipdb
+pub(super) use types::{
+    BelPortPOD, BelWirePOD, LocationPOD, MinimizedBelPortPOD, MinimizedBelWirePOD,
+    MinimizedLocationPOD, MinimizedPIOInfoPOD, MinimizedPackageInfoPOD, MinimizedTileInfoPOD,
+    PIOInfoPOD, PackageInfoPOD, PipLocatorPOD, SpeedGradePOD, TileInfoPOD,
+};
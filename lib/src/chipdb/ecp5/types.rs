@@ -11,10 +11,15 @@ pub struct LocationPOD {
     pub y: i16,
 }
 
+#[derive(Minimize)]
 pub struct BelWirePOD {
+    #[include_rewrite]
     pub rel_wire_loc: LocationPOD,
+    #[include]
     pub wire_index: i32,
+    #[include]
     pub port: i32,
+    #[include]
     pub r#type: i32,
 }
 
@@ -26,12 +31,17 @@ pub struct BelInfoPOD {
     pub r#type: gfx::ecp5::ConstId,
     #[include]
     pub z: i32,
+    #[include_rewrite]
     pub bel_wires: Vec<BelWirePOD>,
 }
 
+#[derive(Minimize)]
 pub struct BelPortPOD {
+    #[include_rewrite]
     pub rel_bel_loc: LocationPOD,
+    #[include]
     pub bel_index: i32,
+    #[include]
     pub port: i32,
 }
 
@@ -45,6 +55,7 @@ pub struct PipInfoPOD {
     pub src_idx: i16,
     #[include]
     pub dst_idx: i16,
+    #[include]
     pub timing_class: i16,
     pub tile_type: i8,
     pub pip_type: i8,
@@ -52,8 +63,11 @@ pub struct PipInfoPOD {
     pub padding: i16,
 }
 
+#[derive(Minimize)]
 pub struct PipLocatorPOD {
+    #[include_rewrite]
     pub rel_loc: LocationPOD,
+    #[include]
     pub index: i32,
 }
 
@@ -65,8 +79,11 @@ pub struct WireInfoPOD {
     pub r#type: gfx::ecp5::ConstId,
     #[include]
     pub tile_wire: gfx::ecp5::GfxTileWireId,
+    #[include_rewrite]
     pub pips_uphill: Vec<PipLocatorPOD>,
+    #[include_rewrite]
     pub pips_downhill: Vec<PipLocatorPOD>,
+    #[include_rewrite]
     pub bel_pins: Vec<BelPortPOD>,
 }
 
@@ -80,74 +97,120 @@ pub struct LocationTypePOD {
     pub pip_data: Vec<PipInfoPOD>,
 }
 
+#[derive(Minimize)]
 pub struct PIOInfoPOD {
+    #[include_rewrite]
     pub abs_loc: LocationPOD,
+    #[include]
     pub bel_index: i32,
+    #[include]
     pub function_name: String,
+    #[include]
     pub bank: i16,
+    #[include]
     pub dqsgroup: i16,
 }
 
+#[derive(Minimize)]
 pub struct PackagePinPOD {
+    #[include]
     pub name: String,
+    #[include_rewrite]
     pub abs_loc: LocationPOD,
+    #[include]
     pub bel_index: i32,
 }
 
+#[derive(Minimize)]
 pub struct PackageInfoPOD {
+    #[include]
     pub name: String,
+    #[include_rewrite]
     pub pin_data: Vec<PackagePinPOD>,
 }
 
+#[derive(Minimize)]
 pub struct TileNamePOD {
+    #[include]
     pub name: String,
+    #[include]
     pub type_idx: i16,
     pub padding: i16,
 }
 
+#[derive(Minimize)]
 pub struct TileInfoPOD {
+    #[include_rewrite]
     pub tile_names: Vec<TileNamePOD>,
 }
 
+#[derive(Minimize)]
 pub struct GlobalInfoPOD {
+    #[include]
     pub tap_col: i16,
+    #[include]
     pub tap_dir: i8,
+    #[include]
     pub quad: i8,
+    #[include]
     pub spine_row: i16,
+    #[include]
     pub spine_col: i16,
 }
 
+#[derive(Minimize)]
 pub struct CellPropDelayPOD {
+    #[include]
     pub from_port: i32,
+    #[include]
     pub to_port: i32,
+    #[include]
     pub min_delay: i32,
+    #[include]
     pub max_delay: i32,
 }
 
+#[derive(Minimize)]
 pub struct CellSetupHoldPOD {
+    #[include]
     pub sig_port: i32,
+    #[include]
     pub clock_port: i32,
+    #[include]
     pub min_setup: i32,
+    #[include]
     pub max_setup: i32,
     pub min_hold: i32,
     pub max_hold: i32,
 }
 
+#[derive(Minimize)]
 pub struct CellTimingPOD {
+    #[include]
     pub cell_type: i32,
+    #[include_rewrite]
     pub prop_delays: Vec<CellPropDelayPOD>,
+    #[include_rewrite]
     pub setup_holds: Vec<CellSetupHoldPOD>,
 }
 
+#[derive(Minimize)]
 pub struct PipDelayPOD {
+    #[include]
     pub min_base_delay: i32,
+    #[include]
     pub max_base_delay: i32,
+    #[include]
     pub min_fanout_adder: i32,
+    #[include]
     pub max_fanout_adder: i32,
 }
 
+#[derive(Minimize)]
 pub struct SpeedGradePOD {
+    #[include_rewrite]
     pub cell_timings: Vec<CellTimingPOD>,
+    #[include_rewrite]
     pub pip_classes: Vec<PipDelayPOD>,
 }
 
@@ -164,10 +227,16 @@ pub struct ChipInfoPOD {
     pub locations: Vec<LocationTypePOD>,
     #[include]
     pub location_type: Vec<i32>,
+    #[include_rewrite]
     pub location_glbinfo: Vec<GlobalInfoPOD>,
+    #[include]
     pub tiletype_names: Vec<String>,
+    #[include_rewrite]
     pub package_info: Vec<PackageInfoPOD>,
+    #[include_rewrite]
     pub pio_info: Vec<PIOInfoPOD>,
+    #[include_rewrite]
     pub tile_info: Vec<TileInfoPOD>,
+    #[include_rewrite]
     pub speed_grades: Vec<SpeedGradePOD>,
 }
@@ -2,9 +2,13 @@
 
 use minimize_derive::Minimize;
 
+#[derive(Minimize)]
 pub struct BelWirePOD {
+    #[include]
     pub port: i32,
+    #[include]
     pub r#type: i32,
+    #[include]
     pub wire_index: i32,
 }
 
@@ -14,6 +18,7 @@ pub struct BelInfoPOD {
     pub name: String,
     #[include]
     pub r#type: i32,
+    #[include_rewrite]
     pub bel_wires: Vec<BelWirePOD>,
     #[include]
     pub x: i8,
@@ -24,8 +29,11 @@ pub struct BelInfoPOD {
     pub padding_0: i8,
 }
 
+#[derive(Minimize)]
 pub struct BelPortPOD {
+    #[include]
     pub bel_index: i32,
+    #[include]
     pub port: i32,
 }
 
@@ -36,7 +44,9 @@ pub struct PipInfoPOD {
     pub src: i32,
     #[include]
     pub dst: i32,
+    #[include]
     pub fast_delay: i32,
+    #[include]
     pub slow_delay: i32,
     #[include]
     pub x: i8,
@@ -74,13 +84,18 @@ pub struct WireInfoPOD {
     pub name_x: i8,
     pub name_y: i8,
     pub padding: i16,
+    #[include]
     pub pips_uphill: Vec<i32>,
+    #[include]
     pub pips_downhill: Vec<i32>,
+    #[include_rewrite]
     pub bel_pins: Vec<BelPortPOD>,
     #[include_rewrite]
     pub segments: Vec<WireSegmentPOD>,
 
+    #[include]
     pub fast_delay: i32,
+    #[include]
     pub slow_delay: i32,
 
     #[include]
@@ -109,13 +124,19 @@ pub struct WireInfoPOD {
     pub r#type: i8,
 }
 
+#[derive(Minimize)]
 pub struct PackagePinPOD {
+    #[include]
     pub name: String,
+    #[include]
     pub bel_index: i32,
 }
 
+#[derive(Minimize)]
 pub struct PackageInfoPOD {
+    #[include]
     pub name: String,
+    #[include_rewrite]
     pub pins: Vec<PackagePinPOD>,
 }
 
@@ -173,15 +194,23 @@ pub struct BelConfigPOD {
     pub entries: Vec<BelConfigEntryPOD>,
 }
 
+#[derive(Minimize)]
 pub struct CellPathDelayPOD {
+    #[include]
     pub from_port: i32,
+    #[include]
     pub to_port: i32,
+    #[include]
     pub fast_delay: i32,
+    #[include]
     pub slow_delay: i32,
 }
 
+#[derive(Minimize)]
 pub struct CellTimingPOD {
+    #[include]
     pub r#type: i32,
+    #[include_rewrite]
     pub path_delays: Vec<CellPathDelayPOD>,
 }
 
@@ -231,7 +260,9 @@ pub struct ChipInfoPOD {
 
     pub bits_info: BitstreamInfoPOD,
     pub bel_config: Vec<BelConfigPOD>,
+    #[include_rewrite]
     pub packages_data: Vec<PackageInfoPOD>,
+    #[include_rewrite]
     pub cell_timing: Vec<CellTimingPOD>,
     pub global_network_info: Vec<GlobalNetworkInfoPOD>,
     pub tile_wire_names: Vec<String>,
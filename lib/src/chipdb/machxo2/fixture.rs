@@ -0,0 +1,94 @@
+use crate::chipdb::bba::Bba;
+
+/// Names of the constids the fixture refers to, as in `constids.inc`.
+pub const CONST_IDS: [&str; 6] = ["TRELLIS_SLICE", "WIRE_TYPE_SLICE", "A0", "F0", "M0", "CLK"];
+
+/// A two tile MachXO2 chipdb. The left tile holds a BEL `SLICEA` driving wire `F0_SLICE`, which a
+/// pip of the right tile connects to its wire `A0_SLICE`. `SLICEA` is bonded to pin `1` of package
+/// `QFN32`, and the chipdb has two speed grades.
+pub fn chipdb() -> Vec<u8> {
+    let mut db = Bba::default();
+
+    let (slice, wire_slice, a0, f0, m0, clk) = (1, 2, 3, 4, 5, 6);
+
+    // Left tile: BEL SLICEA with output F0 on wire F0_SLICE
+    let bel_wires = db.pos();
+    db.i16(0).i16(0).i32(0).i32(f0).i32(1);
+    let slicea = db.string("SLICEA");
+    let left_bels = db.pos();
+    db.ptr(slicea).i32(slice).i32(0).slice(bel_wires, 1);
+    let left_downhill = db.pos();
+    db.i16(1).i16(0).i32(0);
+    let left_bel_pins = db.pos();
+    db.i16(0).i16(0).i32(0).i32(f0);
+    let f0_name = db.string("F0_SLICE");
+    let left_wires = db.pos();
+    db.ptr(f0_name).i32(wire_slice).i32(0);
+    db.slice(0, 0)
+        .slice(left_downhill, 1)
+        .slice(left_bel_pins, 1);
+
+    // Right tile: wire A0_SLICE and the pip F0_SLICE -> A0_SLICE
+    let right_uphill = db.pos();
+    db.i16(0).i16(0).i32(0);
+    let a0_name = db.string("A0_SLICE");
+    let right_wires = db.pos();
+    db.ptr(a0_name).i32(wire_slice).i32(0);
+    db.slice(right_uphill, 1).slice(0, 0).slice(0, 0);
+    let right_pips = db.pos();
+    db.i16(-1).i16(0).i16(0).i16(0);
+    db.i32(0).i32(0).i32(1).i16(0).u8(0).u8(0);
+
+    let tiles = db.pos();
+    db.slice(left_bels, 1).slice(left_wires, 1).slice(0, 0);
+    db.slice(0, 0).slice(right_wires, 1).slice(right_pips, 1);
+
+    let tiletype_names = db.strings(&["PLC"]);
+
+    let pin_name = db.string("1");
+    let pins = db.pos();
+    db.ptr(pin_name).i16(0).i16(0).i32(0);
+    let package_name = db.string("QFN32");
+    let packages = db.pos();
+    db.ptr(package_name).slice(pins, 1);
+
+    let tile_names = [db.string("R1C1"), db.string("R1C2")];
+    let names = db.pos();
+    for name in tile_names {
+        db.ptr(name).i16(0).i16(0);
+    }
+    let tile_info = db.pos();
+    db.slice(names, 1).slice(names + 8, 1);
+
+    // Speed grades 1 and 2: SLICEA A0 -> F0, M0 setup to CLK and two pip classes each
+    let prop_delays = db.pos();
+    db.i32(a0).i32(f0).i32(100).i32(150);
+    db.i32(a0).i32(f0).i32(80).i32(120);
+    let setup_holds = db.pos();
+    db.i32(m0).i32(clk).i32(10).i32(20).i32(0).i32(0);
+    db.i32(m0).i32(clk).i32(8).i32(16).i32(0).i32(0);
+    let cell_timings = db.pos();
+    for i in 0..2 {
+        db.i32(slice);
+        db.slice(prop_delays + 16 * i, 1);
+        db.slice(setup_holds + 24 * i, 1);
+    }
+    let pip_classes = db.pos();
+    db.i32(0).i32(0).i32(0).i32(0);
+    db.i32(50).i32(70).i32(0).i32(5);
+    db.i32(0).i32(0).i32(0).i32(0);
+    db.i32(40).i32(60).i32(0).i32(4);
+    let speed_grades = db.pos();
+    for i in 0..2 {
+        db.slice(cell_timings + 20 * i, 1);
+        db.slice(pip_classes + 32 * i, 2);
+    }
+
+    let root = db.pos();
+    db.i32(2).i32(1).i32(2).i32(CONST_IDS.len() as i32 + 1);
+    db.slice(tiles, 2).slice(tiletype_names, 1);
+    db.slice(packages, 1).slice(0, 0).slice(tile_info, 2);
+    db.slice(speed_grades, 2);
+
+    db.finish(root)
+}
@@ -0,0 +1,153 @@
+use std::io::Cursor;
+
+use crate::chipdb::ecp5::{BelPortPOD, BelWirePOD, LocationPOD, PipLocatorPOD};
+use crate::chipdb::reltypes::{read_relslice, read_relstring, read_relstringarr, ByteArray, POD};
+
+use super::types::{BelInfoPOD, ChipDatabase, ChipInfoPOD, PipInfoPOD, TileTypePOD, WireInfoPOD};
+
+use anyhow::{bail, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// Reads a MachXO2 chipdb. `const_ids` are the names from nextpnr's `machxo2/constids.inc`,
+/// which the chipdb refers to by index only.
+pub fn get_full_chipinfo(chipdata: &[u8], const_ids: &[String]) -> Result<ChipDatabase> {
+    let mut cur = Cursor::new(chipdata);
+
+    let offset = cur.read_u32::<LittleEndian>()?;
+    cur.set_position(offset as u64);
+
+    let chip = ChipInfoPOD::new(&mut cur)?;
+    if const_ids.len() > chip.const_id_count as usize {
+        bail!(
+            "constids.inc has {} ids but the chipdb only {}, are they from the same nextpnr?",
+            const_ids.len(),
+            chip.const_id_count
+        );
+    }
+
+    Ok(ChipDatabase {
+        chip,
+        id_strs: std::iter::once(String::new())
+            .chain(const_ids.iter().cloned())
+            .collect(),
+    })
+}
+
+impl POD for BelInfoPOD {
+    // Size: 20
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            name: read_relstring(cur)?,
+            r#type: cur.read_i32::<LittleEndian>()?,
+            z: cur.read_i32::<LittleEndian>()?,
+            bel_wires: read_relslice::<BelWirePOD>(cur)?,
+        })
+    }
+}
+
+impl POD for PipInfoPOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            rel_src_loc: LocationPOD::new(cur)?,
+            rel_dst_loc: LocationPOD::new(cur)?,
+            src_idx: cur.read_i32::<LittleEndian>()?,
+            dst_idx: cur.read_i32::<LittleEndian>()?,
+            timing_class: cur.read_i32::<LittleEndian>()?,
+            tile_type: cur.read_i16::<LittleEndian>()?,
+            pip_type: cur.read_i8()?,
+            padding: cur.read_i8()?,
+        })
+    }
+}
+
+impl POD for WireInfoPOD {
+    // Size: 36
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            name: read_relstring(cur)?,
+            r#type: cur.read_i32::<LittleEndian>()?,
+            tile_wire: cur.read_i32::<LittleEndian>()?,
+            pips_uphill: read_relslice::<PipLocatorPOD>(cur)?,
+            pips_downhill: read_relslice::<PipLocatorPOD>(cur)?,
+            bel_pins: read_relslice::<BelPortPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for TileTypePOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            bel_data: read_relslice::<BelInfoPOD>(cur)?,
+            wire_data: read_relslice::<WireInfoPOD>(cur)?,
+            pip_data: read_relslice::<PipInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for ChipInfoPOD {
+    // Size: 64
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            width: cur.read_i32::<LittleEndian>()?,
+            height: cur.read_i32::<LittleEndian>()?,
+            num_tiles: cur.read_i32::<LittleEndian>()?,
+            const_id_count: cur.read_i32::<LittleEndian>()?,
+            tiles: read_relslice::<TileTypePOD>(cur)?,
+            tiletype_names: read_relstringarr(cur)?,
+            package_info: read_relslice(cur)?,
+            pio_info: read_relslice(cur)?,
+            tile_info: read_relslice(cur)?,
+            speed_grades: read_relslice(cur)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fixture;
+    use super::*;
+
+    fn const_ids() -> Vec<String> {
+        fixture::CONST_IDS.map(str::to_string).to_vec()
+    }
+
+    #[test]
+    fn reads_tiles_after_the_shared_ecp5_structs() {
+        let db = get_full_chipinfo(&fixture::chipdb(), &const_ids()).unwrap();
+
+        assert_eq!((db.chip.width, db.chip.height), (2, 1));
+        assert_eq!(db.chip.tiles[0].bel_data[0].name, "SLICEA");
+        assert_eq!(db.chip.tiles[0].bel_data[0].bel_wires[0].port, 4);
+        assert_eq!(db.chip.tiles[0].wire_data[0].pips_downhill[0].rel_loc.x, 1);
+        let pip = &db.chip.tiles[1].pip_data[0];
+        assert_eq!((pip.rel_src_loc.x, pip.timing_class), (-1, 1));
+        assert_eq!(db.chip.package_info[0].pin_data[0].name, "1");
+        assert_eq!(db.chip.tile_info[1].tile_names[0].name, "R1C2");
+        assert_eq!(db.id_strs[1], "TRELLIS_SLICE");
+    }
+
+    #[test]
+    fn reads_speed_grades_after_the_tile_info() {
+        let db = get_full_chipinfo(&fixture::chipdb(), &const_ids()).unwrap();
+
+        assert_eq!(db.chip.speed_grades.len(), 2);
+        let grade = &db.chip.speed_grades[1];
+        assert_eq!(grade.pip_classes[1].max_base_delay, 60);
+        assert_eq!(grade.cell_timings[0].prop_delays[0].max_delay, 120);
+        assert_eq!(grade.cell_timings[0].setup_holds[0].max_setup, 16);
+    }
+
+    #[test]
+    fn rejects_constids_of_another_nextpnr() {
+        let mut too_many = const_ids();
+        too_many.extend(["X", "Y"].map(str::to_string));
+        assert!(get_full_chipinfo(&fixture::chipdb(), &too_many).is_err());
+    }
+}
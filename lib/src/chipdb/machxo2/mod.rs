@@ -0,0 +1,8 @@
+mod r#impl;
+mod types;
+
+#[cfg(test)]
+pub(crate) mod fixture;
+
+pub use r#impl::get_full_chipinfo;
+pub use types::{MinimizedChipDatabase, MinimizedTileTypePOD};
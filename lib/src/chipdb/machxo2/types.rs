@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use minimize_derive::Minimize;
+
+// Structs the MachXO2 chipdb shares with ECP5, both being built from the Trellis database
+use crate::chipdb::ecp5::{
+    BelPortPOD, BelWirePOD, LocationPOD, MinimizedBelPortPOD, MinimizedBelWirePOD,
+    MinimizedLocationPOD, MinimizedPIOInfoPOD, MinimizedPackageInfoPOD, MinimizedPipLocatorPOD,
+    MinimizedSpeedGradePOD, MinimizedTileInfoPOD, PIOInfoPOD, PackageInfoPOD, PipLocatorPOD,
+    SpeedGradePOD, TileInfoPOD,
+};
+
+#[derive(Minimize)]
+pub struct BelInfoPOD {
+    #[include]
+    pub name: String,
+    #[include]
+    pub r#type: i32,
+    #[include]
+    pub z: i32,
+    #[include_rewrite]
+    pub bel_wires: Vec<BelWirePOD>,
+}
+
+#[derive(Minimize)]
+pub struct PipInfoPOD {
+    #[include_rewrite]
+    pub rel_src_loc: LocationPOD,
+    #[include_rewrite]
+    pub rel_dst_loc: LocationPOD,
+    #[include]
+    pub src_idx: i32,
+    #[include]
+    pub dst_idx: i32,
+    #[include]
+    pub timing_class: i32,
+    pub tile_type: i16,
+    pub pip_type: i8,
+    pub padding: i8,
+}
+
+#[derive(Minimize)]
+pub struct WireInfoPOD {
+    #[include]
+    pub name: String,
+    #[include]
+    pub r#type: i32,
+    pub tile_wire: i32,
+    #[include_rewrite]
+    pub pips_uphill: Vec<PipLocatorPOD>,
+    #[include_rewrite]
+    pub pips_downhill: Vec<PipLocatorPOD>,
+    #[include_rewrite]
+    pub bel_pins: Vec<BelPortPOD>,
+}
+
+#[derive(Minimize)]
+pub struct TileTypePOD {
+    #[include_rewrite]
+    pub bel_data: Vec<BelInfoPOD>,
+    #[include_rewrite]
+    pub wire_data: Vec<WireInfoPOD>,
+    #[include_rewrite]
+    pub pip_data: Vec<PipInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct ChipInfoPOD {
+    #[include]
+    pub width: i32,
+    #[include]
+    pub height: i32,
+    #[include]
+    pub num_tiles: i32,
+    pub const_id_count: i32,
+    #[include_rewrite]
+    pub tiles: Vec<TileTypePOD>,
+    #[include]
+    pub tiletype_names: Vec<String>,
+    #[include_rewrite]
+    pub package_info: Vec<PackageInfoPOD>,
+    #[include_rewrite]
+    pub pio_info: Vec<PIOInfoPOD>,
+    #[include_rewrite]
+    pub tile_info: Vec<TileInfoPOD>,
+    #[include_rewrite]
+    pub speed_grades: Vec<SpeedGradePOD>,
+}
+
+/// A MachXO2 chipdb with the names of the ids it refers to by index.
+#[derive(Minimize)]
+pub struct ChipDatabase {
+    #[include_rewrite]
+    pub chip: ChipInfoPOD,
+    /// Name of every id by index, the empty id 0 followed by `constids.inc`.
+    #[include]
+    pub id_strs: Vec<String>,
+}
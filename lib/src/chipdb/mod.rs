@@ -1,6 +1,20 @@
 pub mod ecp5;
 pub mod ice40;
+pub mod machxo2;
+pub mod nexus;
 mod reltypes;
 
+#[cfg(test)]
+mod bba;
+
 mod encoder;
 pub use encoder::{decode_min_chipinfo, encode_min_chipinfo};
+
+/// Names declared by a nextpnr `constids.inc`, in order. Chipdbs that refer to these ids by
+/// index need them at minimization, as the viewer has no copy of its own.
+pub fn parse_constids(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|l| l.trim().strip_prefix("X(")?.strip_suffix(')'))
+        .map(|id| id.trim().to_string())
+        .collect()
+}
@@ -0,0 +1,108 @@
+use crate::chipdb::bba::Bba;
+
+use super::types::{LOC_LOGIC, LOGICAL_TO_PRIMARY, WIRE_PRIMARY};
+
+/// Names of the constids the fixture refers to, as in `constids.inc`.
+pub const CONST_IDS: [&str; 2] = ["LUT4", "F"];
+
+/// A two tile Nexus family chipdb. Both tiles hold the wires `A`, primary, and `B`, with a pip
+/// from `B` to `A`. Wire `B` of the right tile is the node named by wire `A` of the left one,
+/// and the left tile holds a BEL `SLICEA` bonded to pin `A1` of package `QFN72`.
+pub fn chipdb() -> Vec<u8> {
+    let mut db = Bba::default();
+
+    // IdStrings after the constids and the `ID3` placeholder
+    let (a, b, slicea, plc, a0) = (4, 5, 6, 7, 8);
+    let (lut4, f) = (1, 2);
+
+    // Loctype 0: BEL SLICEA with output F on wire A, wires A and B, pip B -> A
+    let bel_ports = db.pos();
+    db.i32(f).u16(1).u16(0);
+    let bels = db.pos();
+    db.i32(slicea)
+        .i32(lut4)
+        .i16(0)
+        .i16(0)
+        .i32(0)
+        .slice(bel_ports, 1);
+    let pips_uh = db.i32s(&[0]);
+    let pips_dh = db.i32s(&[0]);
+    let bel_pins = db.pos();
+    db.u32(0).i32(f);
+    let wires = db.pos();
+    db.i32(a).u32(WIRE_PRIMARY);
+    db.slice(pips_uh, 1).slice(0, 0).slice(bel_pins, 1);
+    db.i32(b).u32(0);
+    db.slice(0, 0).slice(pips_dh, 1).slice(0, 0);
+    let pips = db.pos();
+    db.u16(1).u16(0).u16(0).u16(0).i32(0);
+    let loctypes = db.pos();
+    db.slice(bels, 1).slice(wires, 2).slice(pips, 1);
+
+    // Neighbourhood 0 (left tile): wire A continues as wire B of the tile to the right.
+    // Neighbourhood 1 (right tile): wire B is the logical wire of wire A to the left.
+    let left_downhill = db.pos();
+    db.i16(1).i16(0).u16(1).u8(0).u8(0);
+    let right_downhill = db.pos();
+    db.i16(-1).i16(0).u16(0).u8(LOGICAL_TO_PRIMARY).u8(0);
+    let left_wires = db.pos();
+    db.slice(0, 0).slice(left_downhill, 1);
+    db.slice(0, 0).slice(0, 0);
+    let right_wires = db.pos();
+    db.slice(0, 0).slice(0, 0);
+    db.slice(0, 0).slice(right_downhill, 1);
+    let nhtypes = db.pos();
+    db.slice(left_wires, 2).slice(right_wires, 2);
+
+    let phys_tiles = db.pos();
+    db.i32(0).i32(plc);
+    let grid = db.pos();
+    for nh in 0..2 {
+        db.u32(0).u32(LOC_LOGIC).u16(nh).u16(0).slice(phys_tiles, 1);
+    }
+
+    let pad_pins = db.strings(&["A1"]);
+    let pads = db.pos();
+    db.i16(0).i16(0).i16(0).i16(1).i16(-1).i16(-1);
+    db.slice(0, 0).slice(pad_pins, 1);
+    let package_name = db.string("QFN72");
+    let packages = db.pos();
+    db.ptr(package_name).ptr(package_name);
+
+    let device_name = db.string("LIFCL-TEST");
+    let other_name = db.string("LIFCL-OTHER");
+    let chips = db.pos();
+    for name in [device_name, other_name] {
+        db.ptr(name).u16(2).u16(1).slice(grid, 2).i32(0);
+        db.slice(pads, 1).slice(packages, 1);
+    }
+
+    // Speed grades 10 and 12: LUT4 A0 -> F and one pip class each
+    let prop_delays = db.pos();
+    db.i32(a0).i32(f).i32(100).i32(150);
+    db.i32(a0).i32(f).i32(80).i32(120);
+    let cell_types = db.pos();
+    db.i32(lut4).i32(0).slice(prop_delays, 1).slice(0, 0);
+    db.i32(lut4).i32(0).slice(prop_delays + 16, 1).slice(0, 0);
+    let pip_classes = db.pos();
+    db.i32(50).i32(70).i32(0).i32(5);
+    db.i32(40).i32(60).i32(0).i32(4);
+    let grade_names = [db.string("10"), db.string("12")];
+    let speed_grades = db.pos();
+    for (i, name) in grade_names.into_iter().enumerate() {
+        db.ptr(name);
+        db.slice(cell_types + 24 * i as u32, 1);
+        db.slice(pip_classes + 16 * i as u32, 1);
+    }
+
+    let bba_ids = db.strings(&["A", "B", "SLICEA", "PLC", "A0"]);
+    let ids = db.pos();
+    db.u32(4).slice(bba_ids, 5);
+
+    let family = db.string("LIFCL");
+    let root = db.pos();
+    db.u32(1).ptr(family).slice(chips, 2).slice(loctypes, 1);
+    db.slice(nhtypes, 2).slice(speed_grades, 2).ptr(ids);
+
+    db.finish(root)
+}
@@ -0,0 +1,404 @@
+use std::io::Cursor;
+
+use crate::chipdb::reltypes::{
+    read_relptr, read_relslice, read_relstring, read_relstringarr, read_relu32arr, ByteArray, POD,
+};
+
+use super::types::{
+    BelInfoPOD, BelPinPOD, BelWirePOD, CellPropDelayPOD, CellSetupHoldPOD, CellTimingPOD,
+    ChipDatabase, ChipInfoPOD, DatabasePOD, GridLocationPOD, IdStringDBPOD, LocNeighourhoodPOD,
+    LocTypePOD, LocWireInfoPOD, PackageInfoPOD, PadInfoPOD, PhysicalTileInfoPOD, PipInfoPOD,
+    PipTimingPOD, RelWireInfoPOD, SpeedGradePOD, WireNeighboursInfoPOD,
+};
+
+use anyhow::{bail, Result};
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// Reads the device `device` of a Nexus chipdb, or its only device if `None`. `const_ids` are
+/// the names from nextpnr's `nexus/constids.inc`, which the chipdb refers to by index only.
+pub fn get_full_chipinfo(
+    chipdata: &[u8],
+    device: Option<&str>,
+    const_ids: &[String],
+) -> Result<ChipDatabase> {
+    let mut cur = Cursor::new(chipdata);
+
+    let offset = cur.read_u32::<LittleEndian>()?;
+    cur.set_position(offset as u64);
+
+    let db = DatabasePOD::new(&mut cur)?;
+
+    let names: Vec<_> = db.chips.iter().map(|c| c.device_name.clone()).collect();
+    let mut chips = db.chips.into_iter();
+    let chip = match device {
+        Some(device) => chips.find(|c| c.device_name == device),
+        None if names.len() == 1 => chips.next(),
+        None => bail!("Chipdb has several devices, pick one of {names:?}"),
+    };
+    let Some(chip) = chip else {
+        bail!("Chipdb has no device {device:?}, only {names:?}");
+    };
+
+    // IdString 0 is the empty string, followed by the constids and the chipdb's own ids
+    let num_file_ids = db.ids.num_file_ids as usize;
+    if const_ids.len() + 1 > num_file_ids {
+        bail!(
+            "constids.inc has {} ids but the chipdb expects {}, are they from the same nextpnr?",
+            const_ids.len(),
+            num_file_ids - 1
+        );
+    }
+    let id_strs = std::iter::once(String::new())
+        .chain(const_ids.iter().cloned())
+        .chain((const_ids.len() + 1..num_file_ids).map(|i| format!("ID{i}")))
+        .chain(db.ids.bba_id_strs)
+        .collect();
+
+    Ok(ChipDatabase {
+        chip,
+        loctypes: db.loctypes,
+        nhtypes: db.nhtypes,
+        speed_grades: db.speed_grades,
+        id_strs,
+    })
+}
+
+impl POD for BelWirePOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            port: cur.read_i32::<LittleEndian>()?,
+            r#type: cur.read_u16::<LittleEndian>()?,
+            wire_index: cur.read_u16::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for BelInfoPOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            name: cur.read_i32::<LittleEndian>()?,
+            r#type: cur.read_i32::<LittleEndian>()?,
+            rel_x: cur.read_i16::<LittleEndian>()?,
+            rel_y: cur.read_i16::<LittleEndian>()?,
+            z: cur.read_i32::<LittleEndian>()?,
+            ports: read_relslice::<BelWirePOD>(cur)?,
+        })
+    }
+}
+
+impl POD for BelPinPOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            bel: cur.read_u32::<LittleEndian>()?,
+            pin: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for LocWireInfoPOD {
+    // Size: 32
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            name: cur.read_i32::<LittleEndian>()?,
+            flags: cur.read_u32::<LittleEndian>()?,
+            pips_uh: read_relu32arr(cur)?,
+            pips_dh: read_relu32arr(cur)?,
+            bel_pins: read_relslice::<BelPinPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for PipInfoPOD {
+    // Size: 12
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            from_wire: cur.read_u16::<LittleEndian>()?,
+            to_wire: cur.read_u16::<LittleEndian>()?,
+            flags: cur.read_u16::<LittleEndian>()?,
+            timing_class: cur.read_u16::<LittleEndian>()?,
+            tile_type: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for LocTypePOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            bels: read_relslice::<BelInfoPOD>(cur)?,
+            wires: read_relslice::<LocWireInfoPOD>(cur)?,
+            pips: read_relslice::<PipInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for PhysicalTileInfoPOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            prefix: cur.read_i32::<LittleEndian>()?,
+            tiletype: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for GridLocationPOD {
+    // Size: 20
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            loc_type: cur.read_u32::<LittleEndian>()?,
+            loc_flags: cur.read_u32::<LittleEndian>()?,
+            neighbourhood_type: cur.read_u16::<LittleEndian>()?,
+            padding: cur.read_u16::<LittleEndian>()?,
+            phys_tiles: read_relslice::<PhysicalTileInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for RelWireInfoPOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            rel_x: cur.read_i16::<LittleEndian>()?,
+            rel_y: cur.read_i16::<LittleEndian>()?,
+            wire_index: cur.read_u16::<LittleEndian>()?,
+            arc_flags: cur.read_u8()?,
+            padding: cur.read_u8()?,
+        })
+    }
+}
+
+impl POD for WireNeighboursInfoPOD {
+    // Size: 16
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            uphill: read_relslice::<RelWireInfoPOD>(cur)?,
+            downhill: read_relslice::<RelWireInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for LocNeighourhoodPOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            wire_neighbours: read_relslice::<WireNeighboursInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for PadInfoPOD {
+    // Size: 28
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            offset: cur.read_i16::<LittleEndian>()?,
+            side: cur.read_i16::<LittleEndian>()?,
+            pio_index: cur.read_i16::<LittleEndian>()?,
+            bank: cur.read_i16::<LittleEndian>()?,
+            func: cur.read_i16::<LittleEndian>()?,
+            vref_index: cur.read_i16::<LittleEndian>()?,
+            extra_funcs: read_relu32arr(cur)?,
+            pins: read_relstringarr(cur)?,
+        })
+    }
+}
+
+impl POD for PackageInfoPOD {
+    // Size: 8
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            full_name: read_relstring(cur)?,
+            short_name: read_relstring(cur)?,
+        })
+    }
+}
+
+impl POD for ChipInfoPOD {
+    // Size: 36
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            device_name: read_relstring(cur)?,
+            width: cur.read_u16::<LittleEndian>()?,
+            height: cur.read_u16::<LittleEndian>()?,
+            grid: read_relslice::<GridLocationPOD>(cur)?,
+            // Global clock routing isn't drawn, skip over it
+            globals: cur.read_i32::<LittleEndian>()?,
+            pads: read_relslice::<PadInfoPOD>(cur)?,
+            packages: read_relslice::<PackageInfoPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for CellPropDelayPOD {
+    // Size: 16
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            from_port: cur.read_i32::<LittleEndian>()?,
+            to_port: cur.read_i32::<LittleEndian>()?,
+            min_delay: cur.read_i32::<LittleEndian>()?,
+            max_delay: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for CellSetupHoldPOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            sig_port: cur.read_i32::<LittleEndian>()?,
+            clock_port: cur.read_i32::<LittleEndian>()?,
+            min_setup: cur.read_i32::<LittleEndian>()?,
+            max_setup: cur.read_i32::<LittleEndian>()?,
+            min_hold: cur.read_i32::<LittleEndian>()?,
+            max_hold: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for CellTimingPOD {
+    // Size: 24
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            cell_type: cur.read_i32::<LittleEndian>()?,
+            cell_variant: cur.read_i32::<LittleEndian>()?,
+            prop_delays: read_relslice::<CellPropDelayPOD>(cur)?,
+            setup_holds: read_relslice::<CellSetupHoldPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for PipTimingPOD {
+    // Size: 16
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            min_delay: cur.read_i32::<LittleEndian>()?,
+            max_delay: cur.read_i32::<LittleEndian>()?,
+            min_fanout_adder: cur.read_i32::<LittleEndian>()?,
+            max_fanout_adder: cur.read_i32::<LittleEndian>()?,
+        })
+    }
+}
+
+impl POD for SpeedGradePOD {
+    // Size: 20
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            name: read_relstring(cur)?,
+            cell_types: read_relslice::<CellTimingPOD>(cur)?,
+            pip_classes: read_relslice::<PipTimingPOD>(cur)?,
+        })
+    }
+}
+
+impl POD for IdStringDBPOD {
+    // Size: 12
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            num_file_ids: cur.read_u32::<LittleEndian>()?,
+            bba_id_strs: read_relstringarr(cur)?,
+        })
+    }
+}
+
+impl POD for DatabasePOD {
+    // Size: 44
+
+    fn new(cur: &mut Cursor<ByteArray>) -> Result<Self> {
+        Ok(Self {
+            version: cur.read_u32::<LittleEndian>()?,
+            family: read_relstring(cur)?,
+            chips: read_relslice::<ChipInfoPOD>(cur)?,
+            loctypes: read_relslice::<LocTypePOD>(cur)?,
+            nhtypes: read_relslice::<LocNeighourhoodPOD>(cur)?,
+            speed_grades: read_relslice::<SpeedGradePOD>(cur)?,
+            ids: read_relptr::<IdStringDBPOD>(cur)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fixture;
+    use super::*;
+
+    fn const_ids() -> Vec<String> {
+        fixture::CONST_IDS.map(str::to_string).to_vec()
+    }
+
+    #[test]
+    fn reads_the_picked_device() {
+        let db = get_full_chipinfo(&fixture::chipdb(), Some("LIFCL-TEST"), &const_ids()).unwrap();
+
+        assert_eq!(db.chip.device_name, "LIFCL-TEST");
+        assert_eq!((db.chip.width, db.chip.height), (2, 1));
+        assert_eq!(db.chip.grid.len(), 2);
+        assert_eq!(db.chip.grid[1].neighbourhood_type, 1);
+        assert_eq!(db.chip.pads[0].pins, ["A1"]);
+        assert_eq!(db.chip.packages[0].short_name, "QFN72");
+
+        let loctype = &db.loctypes[0];
+        assert_eq!(loctype.bels[0].ports[0].port, 2);
+        assert_eq!(loctype.wires[0].flags, super::super::types::WIRE_PRIMARY);
+        assert_eq!(loctype.wires[1].pips_dh, [0]);
+        assert_eq!((loctype.pips[0].from_wire, loctype.pips[0].to_wire), (1, 0));
+        assert_eq!(db.nhtypes[1].wire_neighbours[1].downhill[0].rel_x, -1);
+    }
+
+    #[test]
+    fn reads_speed_grades_before_the_ids() {
+        let db = get_full_chipinfo(&fixture::chipdb(), Some("LIFCL-TEST"), &const_ids()).unwrap();
+
+        let names: Vec<_> = db.speed_grades.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, ["10", "12"]);
+        assert_eq!(db.speed_grades[1].pip_classes[0].max_delay, 60);
+        assert_eq!(
+            db.speed_grades[0].cell_types[0].prop_delays[0].max_delay,
+            150
+        );
+
+        assert_eq!(
+            db.id_strs,
+            ["", "LUT4", "F", "ID3", "A", "B", "SLICEA", "PLC", "A0"]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_devices_and_constids() {
+        let chipdata = fixture::chipdb();
+
+        let err = get_full_chipinfo(&chipdata, None, &const_ids())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("several devices"));
+        let err = get_full_chipinfo(&chipdata, Some("LIFCL-40"), &const_ids())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("no device"));
+
+        let too_many: Vec<String> = ["LUT4", "F", "Q", "X"].map(str::to_string).to_vec();
+        assert!(get_full_chipinfo(&chipdata, Some("LIFCL-TEST"), &too_many).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+mod r#impl;
+mod types;
+
+#[cfg(test)]
+pub(crate) mod fixture;
+
+pub use r#impl::get_full_chipinfo;
+pub use types::{
+    MinimizedCellTimingPOD, MinimizedChipDatabase, MinimizedGridLocationPOD, MinimizedLocTypePOD,
+    LOC_LOGIC, LOGICAL_TO_PRIMARY, WIRE_PRIMARY,
+};
@@ -0,0 +1,266 @@
+#![allow(dead_code)]
+
+use minimize_derive::Minimize;
+
+// Flag of `LocWireInfoPOD::flags` set on the wire that names a node
+pub const WIRE_PRIMARY: u32 = 0x8000_0000;
+
+// Flag of `RelWireInfoPOD::arc_flags` set on the arc from a tile wire to its primary wire
+pub const LOGICAL_TO_PRIMARY: u8 = 0x80;
+
+// Flag of `GridLocationPOD::loc_flags` set on logic tiles
+pub const LOC_LOGIC: u32 = 0x0000_0001;
+
+#[derive(Minimize)]
+pub struct BelWirePOD {
+    #[include]
+    pub port: i32,
+    #[include]
+    pub r#type: u16,
+    #[include]
+    pub wire_index: u16,
+}
+
+#[derive(Minimize)]
+pub struct BelInfoPOD {
+    #[include]
+    pub name: i32,
+    #[include]
+    pub r#type: i32,
+    #[include]
+    pub rel_x: i16,
+    #[include]
+    pub rel_y: i16,
+    #[include]
+    pub z: i32,
+    #[include_rewrite]
+    pub ports: Vec<BelWirePOD>,
+}
+
+#[derive(Minimize)]
+pub struct BelPinPOD {
+    #[include]
+    pub bel: u32,
+    #[include]
+    pub pin: i32,
+}
+
+#[derive(Minimize)]
+pub struct LocWireInfoPOD {
+    #[include]
+    pub name: i32,
+    #[include]
+    pub flags: u32,
+    #[include]
+    pub pips_uh: Vec<u32>,
+    #[include]
+    pub pips_dh: Vec<u32>,
+    #[include_rewrite]
+    pub bel_pins: Vec<BelPinPOD>,
+}
+
+#[derive(Minimize)]
+pub struct PipInfoPOD {
+    #[include]
+    pub from_wire: u16,
+    #[include]
+    pub to_wire: u16,
+    pub flags: u16,
+    #[include]
+    pub timing_class: u16,
+    pub tile_type: i32,
+}
+
+#[derive(Minimize)]
+pub struct LocTypePOD {
+    #[include_rewrite]
+    pub bels: Vec<BelInfoPOD>,
+    #[include_rewrite]
+    pub wires: Vec<LocWireInfoPOD>,
+    #[include_rewrite]
+    pub pips: Vec<PipInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct PhysicalTileInfoPOD {
+    #[include]
+    pub prefix: i32,
+    #[include]
+    pub tiletype: i32,
+}
+
+#[derive(Minimize)]
+pub struct GridLocationPOD {
+    #[include]
+    pub loc_type: u32,
+    #[include]
+    pub loc_flags: u32,
+    #[include]
+    pub neighbourhood_type: u16,
+    pub padding: u16,
+    #[include_rewrite]
+    pub phys_tiles: Vec<PhysicalTileInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct RelWireInfoPOD {
+    #[include]
+    pub rel_x: i16,
+    #[include]
+    pub rel_y: i16,
+    #[include]
+    pub wire_index: u16,
+    #[include]
+    pub arc_flags: u8,
+    pub padding: u8,
+}
+
+#[derive(Minimize)]
+pub struct WireNeighboursInfoPOD {
+    #[include_rewrite]
+    pub uphill: Vec<RelWireInfoPOD>,
+    #[include_rewrite]
+    pub downhill: Vec<RelWireInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct LocNeighourhoodPOD {
+    #[include_rewrite]
+    pub wire_neighbours: Vec<WireNeighboursInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct PadInfoPOD {
+    #[include]
+    pub offset: i16,
+    #[include]
+    pub side: i16,
+    #[include]
+    pub pio_index: i16,
+    #[include]
+    pub bank: i16,
+    #[include]
+    pub func: i16,
+    pub vref_index: i16,
+    #[include]
+    pub extra_funcs: Vec<u32>,
+    #[include]
+    pub pins: Vec<String>,
+}
+
+#[derive(Minimize)]
+pub struct PackageInfoPOD {
+    #[include]
+    pub full_name: String,
+    #[include]
+    pub short_name: String,
+}
+
+#[derive(Minimize)]
+pub struct ChipInfoPOD {
+    #[include]
+    pub device_name: String,
+    #[include]
+    pub width: u16,
+    #[include]
+    pub height: u16,
+    #[include_rewrite]
+    pub grid: Vec<GridLocationPOD>,
+    pub globals: i32,
+    #[include_rewrite]
+    pub pads: Vec<PadInfoPOD>,
+    #[include_rewrite]
+    pub packages: Vec<PackageInfoPOD>,
+}
+
+#[derive(Minimize)]
+pub struct CellPropDelayPOD {
+    #[include]
+    pub from_port: i32,
+    #[include]
+    pub to_port: i32,
+    #[include]
+    pub min_delay: i32,
+    #[include]
+    pub max_delay: i32,
+}
+
+#[derive(Minimize)]
+pub struct CellSetupHoldPOD {
+    #[include]
+    pub sig_port: i32,
+    #[include]
+    pub clock_port: i32,
+    #[include]
+    pub min_setup: i32,
+    #[include]
+    pub max_setup: i32,
+    pub min_hold: i32,
+    pub max_hold: i32,
+}
+
+#[derive(Minimize)]
+pub struct CellTimingPOD {
+    #[include]
+    pub cell_type: i32,
+    #[include]
+    pub cell_variant: i32,
+    #[include_rewrite]
+    pub prop_delays: Vec<CellPropDelayPOD>,
+    #[include_rewrite]
+    pub setup_holds: Vec<CellSetupHoldPOD>,
+}
+
+#[derive(Minimize)]
+pub struct PipTimingPOD {
+    #[include]
+    pub min_delay: i32,
+    #[include]
+    pub max_delay: i32,
+    #[include]
+    pub min_fanout_adder: i32,
+    #[include]
+    pub max_fanout_adder: i32,
+}
+
+#[derive(Minimize)]
+pub struct SpeedGradePOD {
+    #[include]
+    pub name: String,
+    #[include_rewrite]
+    pub cell_types: Vec<CellTimingPOD>,
+    #[include_rewrite]
+    pub pip_classes: Vec<PipTimingPOD>,
+}
+
+pub struct IdStringDBPOD {
+    pub num_file_ids: u32,
+    pub bba_id_strs: Vec<String>,
+}
+
+pub struct DatabasePOD {
+    pub version: u32,
+    pub family: String,
+    pub chips: Vec<ChipInfoPOD>,
+    pub loctypes: Vec<LocTypePOD>,
+    pub nhtypes: Vec<LocNeighourhoodPOD>,
+    pub speed_grades: Vec<SpeedGradePOD>,
+    pub ids: IdStringDBPOD,
+}
+
+/// One device of a `DatabasePOD`, which holds the whole family, with the tables it shares
+/// with the other devices.
+#[derive(Minimize)]
+pub struct ChipDatabase {
+    #[include_rewrite]
+    pub chip: ChipInfoPOD,
+    #[include_rewrite]
+    pub loctypes: Vec<LocTypePOD>,
+    #[include_rewrite]
+    pub nhtypes: Vec<LocNeighourhoodPOD>,
+    #[include_rewrite]
+    pub speed_grades: Vec<SpeedGradePOD>,
+    /// Name of every `IdString` by index, as the chipdb only stores those after `constids.inc`.
+    #[include]
+    pub id_strs: Vec<String>,
+}
@@ -1,3 +1,7 @@
+use anyhow::{Error, Result};
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -44,3 +48,34 @@ impl ECP5DecalID {
         }
     }
 }
+
+/// Kind of a group decal, stored in its `z` like nextpnr's `GroupId::type`.
+#[allow(non_camel_case_types)]
+#[repr(i8)]
+#[derive(Clone, Copy, PartialEq, FromPrimitive)]
+pub enum ECP5GroupType {
+    TYPE_NONE = 0,
+    TYPE_SWITCHBOX,
+    TYPE_FRAME,
+}
+
+impl TryFrom<i8> for ECP5GroupType {
+    type Error = Error;
+
+    fn try_from(val: i8) -> Result<Self> {
+        match FromPrimitive::from_i8(val) {
+            Some(res) => Ok(res),
+            None => Err(Error::msg("Could not derive ECP5GroupType from value")),
+        }
+    }
+}
+
+impl ECP5GroupType {
+    pub fn name(&self) -> String {
+        match self {
+            ECP5GroupType::TYPE_SWITCHBOX => "SWITCHBOX".to_string(),
+            ECP5GroupType::TYPE_FRAME => "TILE".to_string(),
+            _ => "UNKNOWN_GROUP".to_string(),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 mod ecp5;
 mod ice40;
+mod nexus;
 
 #[allow(dead_code)]
 #[derive(Clone, Serialize, Deserialize)]
@@ -18,5 +19,6 @@ impl<DecalID> DecalXY<DecalID> {
     }
 }
 
-pub use ecp5::{ECP5DecalID, ECP5DecalType};
+pub use ecp5::{ECP5DecalID, ECP5DecalType, ECP5GroupType};
 pub use ice40::{ICE40DecalID, ICE40DecalType, ICE40GroupId, ICE40GroupType, ICE40TileType};
+pub use nexus::{NexusDecalID, NexusDecalType};
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NexusDecalType {
+    TYPE_NONE,
+    TYPE_BEL,
+    TYPE_WIRE,
+    TYPE_PIP,
+    TYPE_GROUP,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct NexusDecalLocation {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A BEL, wire or pip by its index in the location type of tile `location`, or a tile frame.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NexusDecalID {
+    pub r#type: NexusDecalType,
+    pub location: NexusDecalLocation,
+    pub z: f64,
+}
+
+impl Default for NexusDecalID {
+    fn default() -> Self {
+        NexusDecalID {
+            r#type: NexusDecalType::TYPE_NONE,
+            location: NexusDecalLocation { x: 0.0, y: 0.0 },
+            z: 0.0,
+        }
+    }
+}
+
+impl NexusDecalID {
+    pub fn new(r#type: NexusDecalType, x: f64, y: f64, z: f64) -> Self {
+        NexusDecalID {
+            r#type,
+            location: NexusDecalLocation { x, y },
+            z,
+        }
+    }
+}
@@ -5,5 +5,5 @@ mod tilewire;
 pub mod consts;
 
 pub use constids::ConstId;
-pub use gfx::{tile_pip, tile_wire};
+pub use gfx::{get_wire_xy_local, get_wire_xy_main, tile_pip, tile_wire};
 pub use tilewire::GfxTileWireId;
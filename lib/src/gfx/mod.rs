@@ -2,5 +2,95 @@ mod types;
 
 pub use types::{Color, GraphicElement, Style, Type};
 
+/// Declares a nextpnr constid enum, numbered from 1 in declaration order like `constids.inc`,
+/// or from `$first` when given. Ids added by newer chipdbs convert to `Unknown` instead of failing.
+macro_rules! const_ids {
+    ($(#[$attr:meta])* $vis:vis enum $name:ident { $($id:ident),* $(,)? }) => {
+        const_ids! { $(#[$attr])* $vis enum $name = 1 { $($id),* } }
+    };
+    ($(#[$attr:meta])* $vis:vis enum $name:ident = $first:literal { $($id:ident),* $(,)? }) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $($id,)*
+            Unknown(u32),
+        }
+
+        impl $name {
+            /// Value of the id in the chipdb.
+            pub fn index(self) -> u32 {
+                #[derive(Clone, Copy)]
+                enum Index {
+                    $($id,)*
+                }
+                match self {
+                    $($name::$id => Index::$id as u32 + $first,)*
+                    $name::Unknown(val) => val,
+                }
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(val: u32) -> Self {
+                const KNOWN: &[$name] = &[$($name::$id),*];
+                (val as usize)
+                    .checked_sub($first)
+                    .and_then(|i| KNOWN.get(i))
+                    .copied()
+                    .unwrap_or($name::Unknown(val))
+            }
+        }
+    };
+}
+
+/// `const_ids!` with a lookup by name, for chipdbs numbering the same names differently.
+macro_rules! named_const_ids {
+    ($(#[$attr:meta])* $vis:vis enum $name:ident $(= $first:literal)? { $($id:ident),* $(,)? }) => {
+        const_ids! { $(#[$attr])* $vis enum $name $(= $first)? { $($id),* } }
+
+        impl $name {
+            /// The id declared as `name`.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($id) => Some($name::$id),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
 pub mod ecp5;
 pub mod ice40;
+pub mod nexus;
+pub mod package;
+
+/// Placeholder for a BEL type without graphics of its own, e.g. one added by a newer chipdb:
+/// a box crossed out so it stands apart from the known BELs.
+pub fn unknown_bel(x1: f64, y1: f64, x2: f64, y2: f64, style: Style) -> Vec<GraphicElement> {
+    let mut el = GraphicElement::new(Type::Box, style);
+    (el.x1, el.y1, el.x2, el.y2) = (x1, y1, x2, y2);
+    let mut cross = GraphicElement::new(Type::Line, style);
+    (cross.x1, cross.y1, cross.x2, cross.y2) = (x1, y1, x2, y2);
+    let mut other = cross;
+    (other.y1, other.y2) = (y2, y1);
+    vec![el, cross, other]
+}
+
+/// Ticks in the corners of the tile at `x`, `y`, like nextpnr's iCE40 tile frames.
+pub fn tile_frame(x: f64, y: f64) -> Vec<GraphicElement> {
+    let mut g = vec![];
+    for (cx, cy, dx, dy) in [
+        (0.01, 0.01, 0.01, 0.01),
+        (0.99, 0.01, -0.01, 0.01),
+        (0.99, 0.99, -0.01, -0.01),
+        (0.01, 0.99, 0.01, -0.01),
+    ] {
+        let mut el = GraphicElement::new(Type::Line, Style::Frame);
+        (el.x1, el.y1) = (x + cx, y + cy);
+        (el.x2, el.y2) = (x + cx + dx, y + cy);
+        g.push(el);
+        (el.x2, el.y2) = (x + cx, y + cy + dy);
+        g.push(el);
+    }
+    g
+}
@@ -0,0 +1,24 @@
+#![allow(non_upper_case_globals)]
+
+// Tile wires are drawn as short stubs on the left, one slot per wire of the location type
+pub const wire_x1: f64 = 0.05;
+pub const wire_x2: f64 = 0.3;
+pub const wire_y1: f64 = 0.05;
+pub const wire_y2: f64 = 0.95;
+
+// Pips join the stub of their source wire to the slot of their destination wire
+pub const pip_x1: f64 = 0.3;
+pub const pip_x2: f64 = 0.55;
+
+pub const bel_x1: f64 = 0.6;
+pub const bel_x2: f64 = 0.95;
+pub const bel_y1: f64 = 0.05;
+pub const bel_y2: f64 = 0.95;
+// Share of its slot a stacked BEL fills
+pub const bel_fill: f64 = 0.8;
+
+// Logic tiles have four slices of LUT0/FF0, LUT1/FF1 and RAMW rows
+pub const logic_slices: f64 = 4.0;
+pub const logic_rows: f64 = 3.0;
+pub const logic_lut_x2: f64 = 0.76;
+pub const logic_ff_x1: f64 = 0.79;
@@ -0,0 +1,92 @@
+use super::consts;
+use crate::gfx;
+
+// `BEL_*` kinds in the low bits of a logic tile BEL's z, the slice is above them
+const BEL_LUT0: i32 = 0;
+const BEL_LUT1: i32 = 1;
+const BEL_FF0: i32 = 2;
+const BEL_FF1: i32 = 3;
+const BEL_RAMW: i32 = 4;
+
+fn slot_y(y: f64, index: usize, count: usize) -> f64 {
+    let pitch = (consts::wire_y2 - consts::wire_y1) / count.max(1) as f64;
+    y + consts::wire_y1 + (index as f64 + 0.5) * pitch
+}
+
+fn bel_box(x1: f64, y1: f64, x2: f64, y2: f64, style: &gfx::Style) -> Vec<gfx::GraphicElement> {
+    let mut el = gfx::GraphicElement::new(gfx::Type::Box, *style);
+    (el.x1, el.y1, el.x2, el.y2) = (x1, y1, x2, y2);
+    vec![el]
+}
+
+/// BEL `index` of the `count` in a tile. Logic tile BELs are laid out by slice from their z,
+/// all others are stacked in index order.
+pub fn tile_bel(
+    x: f64,
+    y: f64,
+    z: i32,
+    logic: bool,
+    index: usize,
+    count: usize,
+    style: &gfx::Style,
+) -> Vec<gfx::GraphicElement> {
+    if logic {
+        let slice_pitch = (consts::bel_y2 - consts::bel_y1) / consts::logic_slices;
+        let row_pitch = slice_pitch / consts::logic_rows;
+        let (x1, x2, row) = match z & 7 {
+            BEL_LUT0 => (consts::bel_x1, consts::logic_lut_x2, 0),
+            BEL_LUT1 => (consts::bel_x1, consts::logic_lut_x2, 1),
+            BEL_FF0 => (consts::logic_ff_x1, consts::bel_x2, 0),
+            BEL_FF1 => (consts::logic_ff_x1, consts::bel_x2, 1),
+            BEL_RAMW => (consts::bel_x1, consts::bel_x2, 2),
+            _ => (0.0, 0.0, -1),
+        };
+        if row >= 0 {
+            let y1 = y + consts::bel_y1 + (z >> 3) as f64 * slice_pitch + row as f64 * row_pitch;
+            return bel_box(x + x1, y1, x + x2, y1 + row_pitch * consts::bel_fill, style);
+        }
+    }
+
+    let pitch = (consts::bel_y2 - consts::bel_y1) / count.max(1) as f64;
+    let y1 = y + consts::bel_y1 + index as f64 * pitch;
+    bel_box(
+        x + consts::bel_x1,
+        y1,
+        x + consts::bel_x2,
+        y1 + pitch * consts::bel_fill,
+        style,
+    )
+}
+
+/// Stub of wire `index` of the `count` in a tile.
+pub fn tile_wire(
+    x: f64,
+    y: f64,
+    index: usize,
+    count: usize,
+    style: &gfx::Style,
+) -> Vec<gfx::GraphicElement> {
+    let mut el = gfx::GraphicElement::new(gfx::Type::Line, *style);
+    el.x1 = x + consts::wire_x1;
+    el.x2 = x + consts::wire_x2;
+    el.y1 = slot_y(y, index, count);
+    el.y2 = el.y1;
+    vec![el]
+}
+
+/// Pip between the wires `src` and `dst` of the `count` in its tile.
+pub fn tile_pip(
+    x: f64,
+    y: f64,
+    src: usize,
+    dst: usize,
+    count: usize,
+    style: &gfx::Style,
+) -> Vec<gfx::GraphicElement> {
+    let mut el = gfx::GraphicElement::new(gfx::Type::Arrow, *style);
+    el.x1 = x + consts::pip_x1;
+    el.x2 = x + consts::pip_x2;
+    el.y1 = slot_y(y, src, count);
+    el.y2 = slot_y(y, dst, count);
+    vec![el]
+}
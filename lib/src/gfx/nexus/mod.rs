@@ -0,0 +1,5 @@
+mod gfx;
+
+pub mod consts;
+
+pub use gfx::{tile_bel, tile_pip, tile_wire};
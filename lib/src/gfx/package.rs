@@ -0,0 +1,61 @@
+// Row letters JEDEC skips in ball grid names to avoid confusion with digits
+const SKIPPED_ROW_LETTERS: [char; 6] = ['I', 'O', 'Q', 'S', 'X', 'Z'];
+
+fn split_ball_name(name: &str) -> Option<(&str, u32)> {
+    let split = name.find(|c: char| c.is_ascii_digit())?;
+    let (row, col) = name.split_at(split);
+    if row.is_empty() || !row.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    Some((row, col.parse().ok()?))
+}
+
+/// Row index of a ball grid row name (`A`, `B`, …, `Y`, `AA`, `AB`, …).
+fn ball_row(row: &str) -> u32 {
+    let letters = ('A'..='Z')
+        .filter(|c| !SKIPPED_ROW_LETTERS.contains(c))
+        .collect::<Vec<_>>();
+    row.chars().fold(0, |acc, c| {
+        let index = letters.iter().position(|&l| l == c).unwrap_or(0) as u32;
+        acc * letters.len() as u32 + index + 1
+    }) - 1
+}
+
+/// Lays out package pins in package units: ball grid packages by row letter and column number,
+/// numbered packages (QFN, TQFP) counter-clockwise around a square starting at the top left.
+pub fn pin_positions(pins: &[&str]) -> Vec<(f64, f64)> {
+    if pins.iter().all(|p| p.parse::<u32>().is_ok()) {
+        let side = pins.len().div_ceil(4).max(1) as f64;
+        return pins
+            .iter()
+            .map(|p| {
+                let index = p.parse::<u32>().unwrap_or(1).saturating_sub(1) as f64;
+                let (edge, offset) = ((index / side).floor(), index % side);
+                match edge as u32 {
+                    0 => (-1.0, offset),
+                    1 => (offset, side),
+                    2 => (side, side - 1.0 - offset),
+                    _ => (side - 1.0 - offset, -1.0),
+                }
+            })
+            .collect();
+    }
+
+    // Names that are not grid positions go into an extra row below the grid
+    let rows = pins
+        .iter()
+        .filter_map(|p| split_ball_name(p))
+        .map(|(row, _)| ball_row(row))
+        .max()
+        .map_or(0, |r| r + 1);
+    let mut extra = 0.0;
+    pins.iter()
+        .map(|p| match split_ball_name(p) {
+            Some((row, col)) => (col.saturating_sub(1) as f64, ball_row(row) as f64),
+            None => {
+                extra += 1.0;
+                (extra - 1.0, rows as f64 + 1.0)
+            }
+        })
+        .collect()
+}
@@ -66,6 +66,17 @@ impl Color {
     pub fn float_b(&self) -> f32 {
         (self.b as f32).clamp(0.0, 255.0) / 255.0
     }
+
+    /// Linearly interpolates between `self` (at `t = 0`) and `other` (at `t = 1`).
+    pub fn mix(&self, other: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1,14 +1,71 @@
-import {CellColorConfig, Color, ElementType, NextpnrJson, ColorConfig as RendererColorConfig, ReportJson} from '../pkg';
+import {
+    BelPinInfo,
+    CandidateRoute,
+    CellColorConfig,
+    ClockCrossing,
+    ClockDomain,
+    ClockNetworkUsage,
+    Color,
+    Cone,
+    ConeDirection,
+    ElementType,
+    EstimatedPath,
+    NetDelay,
+    NetDetour,
+    NextpnrJson,
+    PackagePinInfo,
+    PathSegmentInfo,
+    PathStep,
+    PipDelay,
+    ColorConfig as RendererColorConfig,
+    RegionUsage,
+    ReportJson,
+    ResourceDelay,
+    RouteCost,
+    RouteInfo,
+    UtilizationSummary,
+    WirePips
+} from '../pkg';
 
 import {SUPPORTED_DEVICES, SupportedChip, SupportedFamily, getChipDbUrl} from './types';
 import {WorkerViewerAdapter} from './worker';
 
-export {NextpnrJson, ReportJson, SUPPORTED_DEVICES, SupportedChip, SupportedFamily};
+export {
+    BelPinInfo,
+    CandidateRoute,
+    ClockCrossing,
+    ClockDomain,
+    ClockNetworkUsage,
+    Cone,
+    ConeDirection,
+    ElementType,
+    EstimatedPath,
+    NetDelay,
+    NetDetour,
+    NextpnrJson,
+    PackagePinInfo,
+    PathSegmentInfo,
+    PathStep,
+    PipDelay,
+    RegionUsage,
+    ReportJson,
+    ResourceDelay,
+    RouteCost,
+    RouteInfo,
+    SUPPORTED_DEVICES,
+    SupportedChip,
+    SupportedFamily,
+    UtilizationSummary,
+    WirePips
+};
 
 interface DecalInfo {
     id: string;
     is_active: boolean;
     is_critical: boolean;
+    tiles: string[];
+    delays: ResourceDelay[];
+    pins: BelPinInfo[];
     internal: any;
 }
 
@@ -98,6 +155,7 @@ export class NextPNRViewer {
     private startedRendering = false;
     private viewerDead = false;
     private animFrameId: number | null = null;
+    private routePlayback: {steps: number; step: number; stepMs: number; timer: number | null} | null = null;
 
     constructor(container: HTMLDivElement, config?: Partial<ViewerConfig>) {
         this.config = {...defaultConfig, ...config};
@@ -161,6 +219,281 @@ export class NextPNRViewer {
         await viewer.show_json(nextpnrJson, reportJson, this.startedRendering);
     }
 
+    async highlightCone(
+        elementType: ElementType,
+        decalId: string,
+        direction: ConeDirection,
+        levels: number = 10,
+        stopAtFf: boolean = true
+    ): Promise<Cone> {
+        this._stopRoutePlayback();
+        return (await this.viewer).highlight_cone(elementType, decalId, direction, levels, stopAtFf);
+    }
+
+    async getWireNet(wire: string): Promise<string | undefined> {
+        return (await this.viewer).get_wire_net(wire);
+    }
+
+    /** Pins of `bel` with their wires and the nets routed through them. */
+    async getBelPins(bel: string): Promise<BelPinInfo[]> {
+        return (await this.viewer).get_bel_pins(bel);
+    }
+
+    /** BEL pins `wire` is attached to. */
+    async getWireBelPins(wire: string): Promise<BelPinInfo[]> {
+        return (await this.viewer).get_wire_bel_pins(wire);
+    }
+
+    /** Select `bel` and highlight the wires on its pins, used ones in the highlight color. */
+    async showBelPins(bel: string): Promise<BelPinInfo[]> {
+        this._stopRoutePlayback();
+        const pins = await (await this.viewer).show_bel_pins(bel);
+        await this._updateSelection(ElementType.Bel, bel);
+        return pins;
+    }
+
+    /** Pips driving into and out of `wire`, each with the wire on its far end. */
+    async getWirePips(wire: string): Promise<WirePips> {
+        return (await this.viewer).get_wire_pips(wire);
+    }
+
+    /** Select `wire` and highlight its uphill and downhill pips; call again with a neighbor wire to walk the routing graph. */
+    async showWirePips(wire: string): Promise<WirePips> {
+        this._stopRoutePlayback();
+        const pips = await (await this.viewer).show_wire_pips(wire);
+        await this._updateSelection(ElementType.Wire, wire);
+        return pips;
+    }
+
+    /** Find and highlight the shortest route from `fromWire` to `toWire` through the routing graph. */
+    async findShortestRoute(
+        fromWire: string,
+        toWire: string,
+        cost: RouteCost = RouteCost.Hops,
+        speed?: string
+    ): Promise<CandidateRoute> {
+        this._stopRoutePlayback();
+        return (await this.viewer).find_shortest_route(fromWire, toWire, cost, speed);
+    }
+
+    /** Find and highlight the shortest route from `fromWire` to the wire on pin `pin` of `bel`. */
+    async findShortestRouteToPin(
+        fromWire: string,
+        bel: string,
+        pin: string,
+        cost: RouteCost = RouteCost.Hops,
+        speed?: string
+    ): Promise<CandidateRoute> {
+        this._stopRoutePlayback();
+        return (await this.viewer).find_shortest_route_to_pin(fromWire, bel, pin, cost, speed);
+    }
+
+    /** Draw the pips the design does not use in the inactive color when zoomed in. */
+    async showUnusedPips(enabled: boolean) {
+        await (await this.viewer).show_unused_pips(enabled);
+    }
+
+    /** Names of all device pips from `offset` on, including the ones the design does not use. */
+    async getDevicePipIds(offset: number, count: number): Promise<string[]> {
+        return (await this.viewer).get_device_pip_ids(offset, count);
+    }
+
+    /** Names of up to `limit` device pips containing `query`. */
+    async searchPips(query: string, limit: number = 100): Promise<string[]> {
+        return (await this.viewer).search_pips(query, limit);
+    }
+
+    /** Select and zoom to any device pip by name, whether or not the design uses it. */
+    async selectPip(pip: string) {
+        await (await this.viewer).select(ElementType.Pip, pip);
+        await this._updateSelection(ElementType.Pip, pip);
+    }
+
+    async getRoute(net: string): Promise<RouteInfo> {
+        return (await this.viewer).get_route(net);
+    }
+
+    async highlightRoute(net: string, sinkWire?: string) {
+        this._stopRoutePlayback();
+        await (await this.viewer).highlight_route(net, sinkWire);
+    }
+
+    /** Shade tiles (ECP5) or switchboxes (iCE40) by how many of their pips are used. */
+    async showCongestion(): Promise<RegionUsage[]> {
+        return (await this.viewer).show_congestion();
+    }
+
+    /**
+     * Shade each `binSize`×`binSize` block of tiles by the fraction of its BELs that are occupied,
+     * optionally only counting BELs of `belType` (e.g. `TRELLIS_COMB` or `ICESTORM_LC`).
+     */
+    async showUtilization(belType?: string, binSize: number = 1): Promise<UtilizationSummary> {
+        return (await this.viewer).show_utilization(belType, binSize);
+    }
+
+    /** Draw the global clock quadrants, spines and taps, highlighting the ones in use. */
+    async showClockNetwork(): Promise<ClockNetworkUsage> {
+        return (await this.viewer).show_clock_network();
+    }
+
+    async clearOverlay() {
+        await (await this.viewer).clear_overlay();
+    }
+
+    /** Color sequential cells and their clock nets by clock domain. */
+    async showClockDomains(): Promise<ClockDomain[]> {
+        this._stopRoutePlayback();
+        return (await this.viewer).show_clock_domains();
+    }
+
+    /** List register-to-register paths that cross between clock domains. */
+    async getClockCrossings(): Promise<ClockCrossing[]> {
+        return (await this.viewer).get_clock_crossings();
+    }
+
+    /** Highlight clock domain crossing paths together with their source and destination cells. */
+    async showClockCrossings(): Promise<ClockCrossing[]> {
+        this._stopRoutePlayback();
+        return (await this.viewer).show_clock_crossings();
+    }
+
+    /** Speed grades delays are available for, e.g. `['6', '7', '8']` or `['fast', 'slow']`. */
+    async getSpeedGrades(): Promise<string[]> {
+        return (await this.viewer).get_speed_grades();
+    }
+
+    /** Color the routed pips by intrinsic delay for `speed` (the first speed grade by default). */
+    async showPipDelays(speed?: string): Promise<PipDelay[]> {
+        this._stopRoutePlayback();
+        return (await this.viewer).show_pip_delays(speed);
+    }
+
+    /** Estimated routing delay in ns from the driver of `net` to each of its sink wires, slowest first. */
+    async getNetDelay(net: string, speed?: string): Promise<NetDelay> {
+        return (await this.viewer).get_net_delay(net, speed);
+    }
+
+    /** Estimate the `count` slowest paths of the routed design from chipdb timing, without a report. */
+    async estimatePaths(count: number, speed?: string): Promise<EstimatedPath[]> {
+        return (await this.viewer).estimate_paths(count, speed);
+    }
+
+    /** Re-estimate the report's critical paths, with nextpnr's delays in `report_delay` for comparison. */
+    async estimateCriticalPaths(speed?: string): Promise<EstimatedPath[]> {
+        return (await this.viewer).estimate_critical_paths(speed);
+    }
+
+    /** Bitstream tiles at grid location (`x`, `y`) as `name:type`, e.g. `R12C34:PLC2` (ECP5 only). */
+    async getTileNames(x: number, y: number): Promise<string[]> {
+        return (await this.viewer).get_tile_names(x, y);
+    }
+
+    /** Names of the packages the device is available in. */
+    async getPackages(): Promise<string[]> {
+        return (await this.viewer).get_packages();
+    }
+
+    /** List the bonded pins of a package, with the design IO placed on each. */
+    async getPackagePins(pkg: string): Promise<PackagePinInfo[]> {
+        return (await this.viewer).get_package_pins(pkg);
+    }
+
+    /** Package pin, function, bank and IO standard of an IO BEL, for labelling it. */
+    async getBelPackagePin(pkg: string, bel: string): Promise<PackagePinInfo | undefined> {
+        return (await this.viewer).get_bel_package_pin(pkg, bel);
+    }
+
+    /** Highlight the IO BELs bonded in a package, design IOs in the highlight color. */
+    async showPackagePins(pkg: string): Promise<PackagePinInfo[]> {
+        this._stopRoutePlayback();
+        return (await this.viewer).show_package_pins(pkg);
+    }
+
+    /** Draw the package footprint instead of the die; picking a ball selects its IO BEL. */
+    async showPackageView(pkg: string): Promise<PackagePinInfo[]> {
+        return (await this.viewer).show_package_view(pkg);
+    }
+
+    /** Switch back to the die view, zooming to the selected decal. */
+    async showDieView() {
+        await (await this.viewer).show_die_view();
+    }
+
+    /** List the `count` nets whose routing strays furthest from a direct route. */
+    async getDetours(count: number = 20): Promise<NetDetour[]> {
+        return (await this.viewer).get_detours(count);
+    }
+
+    async highlightDetours(count: number = 20): Promise<NetDetour[]> {
+        this._stopRoutePlayback();
+        return (await this.viewer).highlight_detours(count);
+    }
+
+    /** Move the critical path cursor to the next or previous segment, across paths, and highlight it. */
+    async pathStep(step: PathStep = PathStep.Next): Promise<PathSegmentInfo> {
+        this._stopRoutePlayback();
+        return (await this.viewer).path_step(step);
+    }
+
+    async selectPathSegment(path: number, segment: number): Promise<PathSegmentInfo> {
+        this._stopRoutePlayback();
+        return (await this.viewer).select_path_segment(path, segment);
+    }
+
+    async clearHighlight() {
+        this._stopRoutePlayback();
+        await (await this.viewer).clear_highlight();
+    }
+
+    /**
+     * Animate the routing of `net`, revealing one hop depth every `stepMs` milliseconds.
+     * Resolves to the number of steps once playback has started.
+     */
+    async playRoute(net: string, stepMs: number = 500): Promise<number> {
+        this.pauseRoute();
+        const steps = await (await this.viewer).start_route_playback(net);
+        this.routePlayback = {steps, step: 0, stepMs, timer: null};
+        this.resumeRoute();
+        return steps;
+    }
+
+    pauseRoute() {
+        if (this.routePlayback?.timer != null) {
+            window.clearInterval(this.routePlayback.timer);
+            this.routePlayback.timer = null;
+        }
+    }
+
+    resumeRoute() {
+        const playback = this.routePlayback;
+        if (!playback || playback.timer != null) return;
+
+        playback.timer = window.setInterval(() => {
+            if (playback.step + 1 >= playback.steps) {
+                this.pauseRoute();
+                return;
+            }
+            this.stepRoute(1).catch((error) => {
+                this.pauseRoute();
+                console.error('nextpnr-viewer: route playback failed', error);
+            });
+        }, playback.stepMs);
+    }
+
+    private _stopRoutePlayback() {
+        this.pauseRoute();
+        this.routePlayback = null;
+    }
+
+    /** Move the route playback forward (or backward, for negative `delta`) by `delta` hops. */
+    async stepRoute(delta: number = 1) {
+        const playback = this.routePlayback;
+        if (!playback) throw new Error('No route playback started');
+
+        playback.step = Math.min(Math.max(playback.step + delta, 0), playback.steps - 1);
+        await (await this.viewer).set_route_playback_step(playback.step);
+    }
+
     async resize(width: number, height: number) {
         this._doResize(width, height);
 
@@ -312,6 +645,7 @@ export class NextPNRViewer {
     private _markViewerDead(error: unknown) {
         if (this.viewerDead) return;
         this.viewerDead = true;
+        this.pauseRoute();
         void this._destroyViewer();
 
         // Cancel any pending animation frame immediately so that no further wasm
@@ -815,6 +1149,20 @@ export class NextPNRViewer {
 
         addRow((decal?.is_active ? '●' : '○') + ' Is active', decal.is_active);
         addRow((decal?.is_critical ? '▲' : '△') + ' Is critical', decal.is_critical);
+        if (decal.tiles.length > 0) {
+            addRow('Tiles', decal.tiles.join(', '));
+        }
+        for (const delay of decal.delays) {
+            const value = delay.min === delay.max ? `${delay.max} ps` : `${delay.min}–${delay.max} ps`;
+            addRow(`Delay (${delay.speed})`, value);
+        }
+        for (const pin of decal.pins) {
+            // A BEL lists the wires on its pins, a wire the BEL pins it reaches
+            const isBel = pin.bel === decal.id;
+            const key = isBel ? `Pin ${pin.pin} (${pin.direction})` : `Pin ${pin.bel}.${pin.pin}`;
+            const target = isBel ? pin.wire : pin.direction;
+            addRow(key, pin.net ? `${target} [${pin.net}]` : target);
+        }
         for (const [key, value] of Object.entries(flattenObject(decal.internal))) {
             addRow(key, value);
         }
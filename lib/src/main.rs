@@ -1,4 +1,4 @@
-use nextpnr_renderer::chipdb::{self, ecp5, ice40};
+use nextpnr_renderer::chipdb::{self, ecp5, ice40, machxo2, nexus};
 use std::{
     env,
     fs::File,
@@ -7,7 +7,10 @@ use std::{
 };
 
 fn print_usage_and_exit() -> ! {
-    eprintln!("Usage: chipdb_minimizer --arch <ice40|ecp5> --input <path> --output <path>");
+    eprintln!(
+        "Usage: chipdb_minimizer --arch <ice40|ecp5|machxo2|nexus> --input <path> --output <path> \
+         [--device <name>] [--constids <path>]"
+    );
     exit(1);
 }
 
@@ -17,6 +20,8 @@ fn main() {
     let mut arch = None;
     let mut input = None;
     let mut output = None;
+    let mut device = None;
+    let mut constids = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -45,6 +50,22 @@ fn main() {
                     print_usage_and_exit();
                 }
             }
+            "--device" => {
+                i += 1;
+                if i < args.len() {
+                    device = Some(args[i].clone());
+                } else {
+                    print_usage_and_exit();
+                }
+            }
+            "--constids" => {
+                i += 1;
+                if i < args.len() {
+                    constids = Some(args[i].clone());
+                } else {
+                    print_usage_and_exit();
+                }
+            }
             _ => {
                 print_usage_and_exit();
             }
@@ -95,6 +116,48 @@ fn main() {
             let min: ice40::MinimizedChipInfoPOD = chipinfo.into();
             chipdb::encode_min_chipinfo(min).unwrap()
         }
+        "machxo2" => {
+            let constids = constids.unwrap_or_else(|| {
+                eprintln!(
+                    "Missing --constids argument, machxo2 chipdbs need nextpnr's machxo2/constids.inc"
+                );
+                print_usage_and_exit();
+            });
+            let constids = std::fs::read_to_string(&constids).unwrap_or_else(|err| {
+                eprintln!("Failed to read constids file {constids}: {err}");
+                exit(1);
+            });
+            let chipinfo = machxo2::get_full_chipinfo(&buf, &chipdb::parse_constids(&constids))
+                .unwrap_or_else(|err| {
+                    eprintln!("Failed to parse MachXO2 chipdb: {err}");
+                    exit(1);
+                });
+            let min: machxo2::MinimizedChipDatabase = chipinfo.into();
+            chipdb::encode_min_chipinfo(min).unwrap()
+        }
+        "nexus" => {
+            let constids = constids.unwrap_or_else(|| {
+                eprintln!(
+                    "Missing --constids argument, nexus chipdbs need nextpnr's nexus/constids.inc"
+                );
+                print_usage_and_exit();
+            });
+            let constids = std::fs::read_to_string(&constids).unwrap_or_else(|err| {
+                eprintln!("Failed to read constids file {constids}: {err}");
+                exit(1);
+            });
+            let chipinfo = nexus::get_full_chipinfo(
+                &buf,
+                device.as_deref(),
+                &chipdb::parse_constids(&constids),
+            )
+            .unwrap_or_else(|err| {
+                eprintln!("Failed to parse Nexus chipdb: {err}");
+                exit(1);
+            });
+            let min: nexus::MinimizedChipDatabase = chipinfo.into();
+            chipdb::encode_min_chipinfo(min).unwrap()
+        }
         _ => {
             eprintln!("Unsupported architecture: {arch}");
             print_usage_and_exit();
@@ -1,24 +1,41 @@
+mod netlist;
 mod nextpnr;
 mod nextpnr_types;
 mod report;
 mod report_types;
+mod routing;
+mod timing;
 
 use anyhow::Result;
+use itertools::Itertools;
+pub use netlist::{
+    CellIndex, ClockCrossingInfo, Cone, ConeDirection, NetIndex, Netlist, NetlistCell,
+};
 pub use nextpnr_types::{INextpnrJSON, NextpnrJson};
+use rustc_hash::{FxHashMap, FxHashSet};
 
-pub use report_types::{IReportJSON, ReportJson};
+pub use report::{PathSegmentInfo, PathStep};
+pub use report_types::{Fmax, IReportJSON, ReportJson, Utilization};
+use routing::grid_location;
+pub use routing::{NetDetour, RouteInfo, RouteNode, RoutingTree};
+pub use timing::{DelayModel, EstimatedPath, PinDelay};
 
 use crate::pnrjson::nextpnr::{NextpnrElements, RoutingPart};
 
 pub enum Chip {
     ICE40,
     ECP5,
+    MachXO2,
+    Nexus,
 }
 
 pub struct PnrInfo {
     chip: Chip,
     nextpnr_json: NextpnrJson,
     report_json: Option<ReportJson>,
+    netlist: Netlist,
+    /// Node driving each routed pip whose source wire doesn't name it, see `set_pip_sources`.
+    pip_sources: FxHashMap<String, String>,
 }
 
 impl PnrInfo {
@@ -27,17 +44,122 @@ impl PnrInfo {
         nextpnr: INextpnrJSON,
         report: Option<IReportJSON>,
     ) -> Result<Self> {
-        Ok(Self {
+        Ok(Self::new(
             chip,
-            nextpnr_json: NextpnrJson::from_jsobj(nextpnr)?,
-            report_json: report.map(ReportJson::from_jsobj).transpose()?,
-        })
+            NextpnrJson::from_jsobj(nextpnr)?,
+            report.map(ReportJson::from_jsobj).transpose()?,
+        ))
+    }
+
+    pub fn new(chip: Chip, nextpnr_json: NextpnrJson, report_json: Option<ReportJson>) -> Self {
+        let netlist = Netlist::new(&nextpnr_json);
+
+        Self {
+            chip,
+            nextpnr_json,
+            report_json,
+            netlist,
+            pip_sources: FxHashMap::default(),
+        }
+    }
+
+    /// Sets the node driving each routed pip by pip name, for architectures whose pip source
+    /// wires are tile wires that don't name the node, like Nexus. Routing trees join those pips
+    /// to their driver through it.
+    pub fn set_pip_sources(&mut self, pip_sources: FxHashMap<String, String>) {
+        self.pip_sources = pip_sources;
+    }
+
+    pub fn get_netlist(&self) -> &Netlist {
+        &self.netlist
+    }
+
+    pub fn get_net_routing(&self, net: &String) -> Vec<RoutingPart> {
+        self.nextpnr_json
+            .get_netname(net)
+            .map(|n| n.get_routing(&self.chip))
+            .unwrap_or_default()
+    }
+
+    pub fn get_routing_tree(&self, net: &String) -> Option<RoutingTree> {
+        self.nextpnr_json
+            .get_netname(net)
+            .map(|n| n.get_routing_tree(net, &self.chip, &self.pip_sources))
+    }
+
+    /// Worst detour of every routed net, largest detour ratio first. `wire_bels` lists the BELs
+    /// with a pin on a wire, used to find the sink cell a routed wire ends in.
+    pub fn get_detours(&self, wire_bels: impl Fn(&str) -> Vec<String>) -> Vec<NetDetour> {
+        let netlist = &self.netlist;
+        self.nextpnr_json
+            .modules
+            .top
+            .netnames
+            .iter()
+            .filter_map(|(name, n)| {
+                let net = netlist.find_net(name).map(|i| &netlist.nets[i]);
+                let driver = net
+                    .and_then(|n| n.driver.as_ref())
+                    .and_then(|d| grid_location(&netlist.cells[d.cell].bel));
+                let sink_bels: FxHashSet<&str> = net
+                    .into_iter()
+                    .flat_map(|n| &n.sinks)
+                    .map(|s| netlist.cells[s.cell].bel.as_str())
+                    .collect();
+                let sink_location = |wire: &str| {
+                    wire_bels(wire)
+                        .iter()
+                        .filter(|bel| sink_bels.contains(bel.as_str()))
+                        .find_map(|bel| grid_location(bel))
+                };
+
+                n.get_routing_tree(name, &self.chip, &self.pip_sources)
+                    .get_worst_detour(driver, sink_location)
+            })
+            .sorted_by(|a, b| {
+                b.ratio
+                    .total_cmp(&a.ratio)
+                    .then(b.routed_length.cmp(&a.routed_length))
+            })
+            .collect()
+    }
+
+    pub fn get_net_wires(&self, net: &String) -> Vec<String> {
+        self.nextpnr_json
+            .get_netname(net)
+            .map(|n| n.get_wires().map(str::to_string).collect())
+            .unwrap_or_default()
     }
 
     pub fn get_elements(&'_ self) -> NextpnrElements<'_> {
         self.nextpnr_json.get_elements(&self.chip)
     }
 
+    pub fn get_path_segment(&self, path: usize, segment: usize) -> Option<PathSegmentInfo> {
+        self.report_json.as_ref()?.get_path_segment(path, segment)
+    }
+
+    pub fn get_fmax(&self, clock: &str) -> Option<&Fmax> {
+        self.report_json.as_ref()?.fmax.get(clock)
+    }
+
+    pub fn get_utilization(&self, bel_type: &str) -> Option<&Utilization> {
+        self.report_json.as_ref()?.utilization.get(bel_type)
+    }
+
+    /// Re-estimates the report's critical paths with `model`, `None` without a report.
+    pub fn estimate_critical_paths(&self, model: &impl DelayModel) -> Option<Vec<EstimatedPath>> {
+        let report = self.report_json.as_ref()?;
+
+        Some(
+            report
+                .critical_paths
+                .iter()
+                .map(|p| self.netlist.estimate_report_path(model, p))
+                .collect(),
+        )
+    }
+
     pub fn get_critical_netnames(&self) -> Vec<RoutingPart> {
         let Some(report) = &self.report_json else {
             return vec![];
@@ -51,3 +173,38 @@ impl PnrInfo {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pnr_info(report: Option<serde_json::Value>) -> PnrInfo {
+        let design = json!({ "modules": { "top": { "cells": {}, "netnames": {} } } });
+        PnrInfo::new(
+            Chip::ECP5,
+            serde_json::from_value(design).unwrap(),
+            report.map(|r| serde_json::from_value(r).unwrap()),
+        )
+    }
+
+    #[test]
+    fn looks_up_fmax_by_clock_net() {
+        let info = pnr_info(Some(json!({
+            "critical_paths": [],
+            "fmax": { "clk$TRELLIS_IO_IN": { "achieved": 152.3, "constraint": 100.0 } },
+        })));
+
+        let fmax = info.get_fmax("clk$TRELLIS_IO_IN").unwrap();
+        assert_eq!((fmax.achieved, fmax.constraint), (152.3, 100.0));
+        assert!(info.get_fmax("clk").is_none());
+    }
+
+    #[test]
+    fn has_no_fmax_without_a_report() {
+        assert!(pnr_info(None).get_fmax("clk").is_none());
+        assert!(pnr_info(Some(json!({ "critical_paths": [] })))
+            .get_fmax("clk")
+            .is_none());
+    }
+}
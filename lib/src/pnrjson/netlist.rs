@@ -0,0 +1,533 @@
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::pnrjson::nextpnr_types::{Bit, PortDirection};
+use crate::pnrjson::NextpnrJson;
+
+pub type CellIndex = usize;
+pub type NetIndex = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ConeDirection {
+    FanIn,
+    FanOut,
+}
+
+pub struct PortRef {
+    pub cell: CellIndex,
+}
+
+pub struct NetlistCell {
+    pub name: String,
+    pub r#type: String,
+    pub bel: String,
+    pub parameters: FxHashMap<String, String>,
+    /// IO standard of an IO cell (ECP5 `IO_TYPE` attribute, iCE40 `IO_STANDARD` parameter).
+    pub io_standard: Option<String>,
+    pub inputs: Vec<(String, NetIndex)>,
+    pub outputs: Vec<(String, NetIndex)>,
+}
+
+pub struct NetlistNet {
+    pub name: String,
+    pub driver: Option<PortRef>,
+    pub sinks: Vec<PortRef>,
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const ICONE: &'static str = r#"
+interface Cone {
+    cells: string[],
+    nets: string[],
+}
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const ICLOCK_CROSSING: &'static str = r#"
+interface ClockCrossing {
+    from_cell: string,
+    to_cell: string,
+    from_clock: string,
+    to_clock: string,
+    nets: string[],
+}
+"#;
+
+/// A path from a sequential cell to a sequential cell in another clock domain.
+pub struct ClockCrossing {
+    pub from: CellIndex,
+    pub to: CellIndex,
+    /// Nets along the path, from the one driven by `from` to the one into `to`.
+    pub nets: Vec<NetIndex>,
+}
+
+#[derive(Serialize)]
+pub struct ClockCrossingInfo {
+    pub from_cell: String,
+    pub to_cell: String,
+    pub from_clock: String,
+    pub to_clock: String,
+    pub nets: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct Cone {
+    pub cells: Vec<String>,
+    pub nets: Vec<String>,
+}
+
+pub struct Netlist {
+    pub cells: Vec<NetlistCell>,
+    pub nets: Vec<NetlistNet>,
+
+    cell_by_name: FxHashMap<String, CellIndex>,
+    cell_by_bel: FxHashMap<String, CellIndex>,
+    net_by_name: FxHashMap<String, NetIndex>,
+    net_by_wire: FxHashMap<String, NetIndex>,
+}
+
+// Ports that carry a clock rather than data. These are not followed when walking a logic cone,
+// otherwise the fan-in of every flip-flop would climb up the whole clock tree.
+fn clock_ports(cell_type: &str) -> &'static [&'static str] {
+    match cell_type {
+        "TRELLIS_FF" | "ICESTORM_LC" | "ICESTORM_DSP" | "IOLOGIC" | "SIOLOGIC" => &["CLK"],
+        "TRELLIS_COMB" => &["WCK"],
+        "DP16KD" => &["CLKA", "CLKB"],
+        "MULT18X18D" | "ALU54B" => &["CLK0", "CLK1", "CLK2", "CLK3"],
+        "ICESTORM_RAM" => &["RCLK", "WCLK"],
+        "ICESTORM_SPRAM" => &["CLOCK"],
+        "SB_IO" => &["INPUT_CLK", "OUTPUT_CLK"],
+        _ => &[],
+    }
+}
+
+impl NetlistCell {
+    pub fn is_clock_port(&self, port: &str) -> bool {
+        clock_ports(&self.r#type).contains(&port)
+    }
+
+    pub fn is_sequential(&self) -> bool {
+        // iCE40 logic cells only register their output when the DFF is enabled
+        if self.r#type == "ICESTORM_LC"
+            && self.parameters.get("DFF_ENABLE").map(|v| v.trim()) != Some("1")
+        {
+            return false;
+        }
+
+        self.inputs.iter().any(|(port, _)| self.is_clock_port(port))
+    }
+
+    pub fn clock_port(&self) -> Option<&str> {
+        self.inputs
+            .iter()
+            .find(|(port, _)| self.is_clock_port(port))
+            .map(|(port, _)| port.as_str())
+    }
+
+    pub fn clock_net(&self) -> Option<NetIndex> {
+        self.inputs
+            .iter()
+            .find(|(port, _)| self.is_clock_port(port))
+            .map(|&(_, net)| net)
+    }
+}
+
+impl Netlist {
+    pub fn new(json: &NextpnrJson) -> Self {
+        let top = &json.modules.top;
+
+        let mut nets = vec![];
+        let mut net_by_bit = FxHashMap::default();
+        let mut net_by_name = FxHashMap::default();
+        let mut net_by_wire = FxHashMap::default();
+        for (name, netname) in top.netnames.iter() {
+            let index = nets.len();
+            net_by_name.insert(name.clone(), index);
+            for bit in &netname.bits {
+                if let Bit::Net(b) = bit {
+                    net_by_bit.insert(*b, index);
+                }
+            }
+            for wire in netname.get_wires() {
+                net_by_wire.insert(wire.to_string(), index);
+            }
+            nets.push(NetlistNet {
+                name: name.clone(),
+                driver: None,
+                sinks: vec![],
+            });
+        }
+
+        let mut cells = vec![];
+        let mut cell_by_name = FxHashMap::default();
+        let mut cell_by_bel = FxHashMap::default();
+        for (name, cell) in top.cells.iter() {
+            let index = cells.len();
+            let mut inputs = vec![];
+            let mut outputs = vec![];
+
+            for (port, bits) in cell.connections.iter() {
+                let Some(&direction) = cell.port_directions.get(port) else {
+                    continue;
+                };
+
+                for bit in bits {
+                    let Bit::Net(b) = bit else {
+                        continue;
+                    };
+                    let Some(&net) = net_by_bit.get(b) else {
+                        continue;
+                    };
+
+                    let port_ref = PortRef { cell: index };
+                    match direction {
+                        PortDirection::Input => {
+                            inputs.push((port.clone(), net));
+                            nets[net].sinks.push(port_ref);
+                        }
+                        PortDirection::Output => {
+                            outputs.push((port.clone(), net));
+                            nets[net].driver = Some(port_ref);
+                        }
+                        PortDirection::Inout => {
+                            inputs.push((port.clone(), net));
+                            outputs.push((port.clone(), net));
+                            nets[net].sinks.push(PortRef { cell: index });
+                            nets[net].driver = Some(port_ref);
+                        }
+                    }
+                }
+            }
+
+            cell_by_name.insert(name.clone(), index);
+            cell_by_bel.insert(cell.attributes.NEXTPNR_BEL.clone(), index);
+            cells.push(NetlistCell {
+                name: name.clone(),
+                r#type: cell.r#type.clone(),
+                bel: cell.attributes.NEXTPNR_BEL.clone(),
+                parameters: cell.parameters.clone(),
+                io_standard: cell
+                    .attributes
+                    .IO_TYPE
+                    .clone()
+                    .or_else(|| cell.parameters.get("IO_STANDARD").cloned()),
+                inputs,
+                outputs,
+            });
+        }
+
+        Self {
+            cells,
+            nets,
+            cell_by_name,
+            cell_by_bel,
+            net_by_name,
+            net_by_wire,
+        }
+    }
+
+    pub fn find_cell(&self, name: &str) -> Option<CellIndex> {
+        self.cell_by_name.get(name).copied()
+    }
+
+    pub fn find_cell_by_bel(&self, bel: &str) -> Option<CellIndex> {
+        self.cell_by_bel.get(bel).copied()
+    }
+
+    pub fn find_net(&self, name: &str) -> Option<NetIndex> {
+        self.net_by_name.get(name).copied()
+    }
+
+    pub fn find_net_by_wire(&self, wire: &str) -> Option<NetIndex> {
+        self.net_by_wire.get(wire).copied()
+    }
+
+    /// Groups sequential cells by the net driving their clock port, sorted by clock net name.
+    pub fn get_clock_domains(&self) -> Vec<(NetIndex, Vec<CellIndex>)> {
+        let mut domains: FxHashMap<NetIndex, Vec<CellIndex>> = FxHashMap::default();
+        for (index, cell) in self.cells.iter().enumerate() {
+            if !cell.is_sequential() {
+                continue;
+            }
+            if let Some(net) = cell.clock_net() {
+                domains.entry(net).or_default().push(index);
+            }
+        }
+
+        domains
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| self.nets[*a].name.cmp(&self.nets[*b].name))
+            .collect()
+    }
+
+    /// Finds paths through combinational logic between sequential cells clocked by different nets.
+    pub fn get_clock_crossings(&self) -> Vec<ClockCrossing> {
+        let mut crossings = vec![];
+
+        for (from, cell) in self.cells.iter().enumerate() {
+            if !cell.is_sequential() {
+                continue;
+            }
+            let Some(from_clock) = cell.clock_net() else {
+                continue;
+            };
+
+            // Breadth-first over nets, remembering the net each one was reached from
+            let mut prev_net: FxHashMap<NetIndex, Option<NetIndex>> = FxHashMap::default();
+            let mut reached: FxHashSet<CellIndex> = FxHashSet::default();
+            let mut frontier: Vec<NetIndex> = vec![];
+            for &(_, net) in &cell.outputs {
+                if prev_net.insert(net, None).is_none() {
+                    frontier.push(net);
+                }
+            }
+
+            while !frontier.is_empty() {
+                let mut next = vec![];
+                for net in frontier {
+                    for sink in &self.nets[net].sinks {
+                        let sink_cell = &self.cells[sink.cell];
+                        // Driving a clock port is a generated clock, not a crossing
+                        if sink_cell.clock_net() == Some(net) || !reached.insert(sink.cell) {
+                            continue;
+                        }
+
+                        if sink_cell.is_sequential() {
+                            if sink_cell.clock_net().is_some_and(|c| c != from_clock) {
+                                let mut nets = vec![net];
+                                while let Some(&Some(prev)) = prev_net.get(nets.last().unwrap()) {
+                                    nets.push(prev);
+                                }
+                                nets.reverse();
+
+                                crossings.push(ClockCrossing {
+                                    from,
+                                    to: sink.cell,
+                                    nets,
+                                });
+                            }
+                            continue;
+                        }
+
+                        for &(_, out) in &sink_cell.outputs {
+                            if let std::collections::hash_map::Entry::Vacant(e) =
+                                prev_net.entry(out)
+                            {
+                                e.insert(Some(net));
+                                next.push(out);
+                            }
+                        }
+                    }
+                }
+                frontier = next;
+            }
+        }
+
+        crossings
+    }
+
+    pub fn get_clock_crossing_info(&self, crossing: &ClockCrossing) -> ClockCrossingInfo {
+        let from = &self.cells[crossing.from];
+        let to = &self.cells[crossing.to];
+        let clock_name = |cell: &NetlistCell| {
+            cell.clock_net()
+                .map(|n| self.nets[n].name.clone())
+                .unwrap_or_default()
+        };
+
+        ClockCrossingInfo {
+            from_cell: from.name.clone(),
+            to_cell: to.name.clone(),
+            from_clock: clock_name(from),
+            to_clock: clock_name(to),
+            nets: crossing
+                .nets
+                .iter()
+                .map(|&n| self.nets[n].name.clone())
+                .collect(),
+        }
+    }
+
+    fn cell_nets(&self, cell: CellIndex, direction: ConeDirection) -> Vec<NetIndex> {
+        let cell = &self.cells[cell];
+        match direction {
+            ConeDirection::FanIn => cell
+                .inputs
+                .iter()
+                .filter(|(port, _)| !cell.is_clock_port(port))
+                .map(|&(_, net)| net)
+                .collect(),
+            ConeDirection::FanOut => cell.outputs.iter().map(|&(_, net)| net).collect(),
+        }
+    }
+
+    fn net_cells(&self, net: NetIndex, direction: ConeDirection) -> Vec<CellIndex> {
+        let net = &self.nets[net];
+        match direction {
+            ConeDirection::FanIn => net.driver.iter().map(|d| d.cell).collect(),
+            ConeDirection::FanOut => net.sinks.iter().map(|s| s.cell).collect(),
+        }
+    }
+
+    /// Walks `levels` cells away from a starting cell or net. Starting from a net, its driver
+    /// (fan-in) or sinks (fan-out) form the first level. With `stop_at_ff`, sequential cells are
+    /// included in the cone but not expanded any further.
+    pub fn get_cone(
+        &self,
+        start_cell: Option<CellIndex>,
+        start_net: Option<NetIndex>,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Cone {
+        let mut cells: FxHashSet<CellIndex> = FxHashSet::from_iter(start_cell);
+        let mut nets: FxHashSet<NetIndex> = FxHashSet::from_iter(start_net);
+
+        let mut frontier_cells: Vec<CellIndex> = start_cell.into_iter().collect();
+        let mut frontier_nets: Vec<NetIndex> = start_net.into_iter().collect();
+
+        for _ in 0..levels {
+            for &cell in &frontier_cells {
+                if stop_at_ff && Some(cell) != start_cell && self.cells[cell].is_sequential() {
+                    continue;
+                }
+                frontier_nets.extend(self.cell_nets(cell, direction));
+            }
+
+            frontier_cells.clear();
+            for net in frontier_nets.drain(..) {
+                nets.insert(net);
+                for cell in self.net_cells(net, direction) {
+                    if cells.insert(cell) {
+                        frontier_cells.push(cell);
+                    }
+                }
+            }
+
+            if frontier_cells.is_empty() {
+                break;
+            }
+        }
+
+        Cone {
+            cells: cells
+                .into_iter()
+                .map(|c| self.cells[c].name.clone())
+                .sorted()
+                .collect(),
+            nets: nets
+                .into_iter()
+                .map(|n| self.nets[n].name.clone())
+                .sorted()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn cell(r#type: &str, bel: &str, ports: &[(&str, &str, u32)]) -> Value {
+        json!({
+            "type": r#type,
+            "attributes": { "NEXTPNR_BEL": bel },
+            "port_directions": ports.iter().map(|(p, d, _)| (*p, *d)).collect::<FxHashMap<_, _>>(),
+            "connections": ports.iter().map(|(p, _, b)| (*p, [b])).collect::<FxHashMap<_, _>>(),
+        })
+    }
+
+    /// `ff_a` in the `clk_a` domain drives `ff_b` in the `clk_b` domain through `lut`, and
+    /// `ff_c` in its own domain. `div` generates `clk_b` from `clk_a`.
+    fn netlist() -> Netlist {
+        let net = |bit: u32| json!({ "bits": [bit], "attributes": { "ROUTING": "" } });
+        let design = json!({ "modules": { "top": {
+            "cells": {
+                "ff_a": cell("TRELLIS_FF", "X1/Y1/SLICEA", &[("CLK", "input", 1), ("Q", "output", 3)]),
+                "lut": cell("TRELLIS_COMB", "X2/Y1/SLICEA", &[("A", "input", 3), ("F", "output", 4)]),
+                "ff_b": cell("TRELLIS_FF", "X3/Y1/SLICEA", &[("CLK", "input", 2), ("DI", "input", 4)]),
+                "ff_c": cell("TRELLIS_FF", "X1/Y2/SLICEA", &[("CLK", "input", 1), ("DI", "input", 3)]),
+                "div": cell("TRELLIS_FF", "X1/Y3/SLICEA", &[("CLK", "input", 1), ("Q", "output", 2)]),
+            },
+            "netnames": {
+                "clk_a": net(1),
+                "clk_b": net(2),
+                "q": net(3),
+                "d": net(4),
+            },
+        } } });
+
+        Netlist::new(&serde_json::from_value(design).unwrap())
+    }
+
+    fn cell_names(netlist: &Netlist, cells: &[CellIndex]) -> Vec<String> {
+        cells
+            .iter()
+            .map(|&c| netlist.cells[c].name.clone())
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn groups_sequential_cells_by_clock_net() {
+        let netlist = netlist();
+        let domains = netlist.get_clock_domains();
+
+        let names: Vec<_> = domains
+            .iter()
+            .map(|(net, cells)| {
+                (
+                    netlist.nets[*net].name.as_str(),
+                    cell_names(&netlist, cells),
+                )
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("clk_a", vec!["div".into(), "ff_a".into(), "ff_c".into()]),
+                ("clk_b", vec!["ff_b".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_paths_between_clock_domains() {
+        let netlist = netlist();
+        let crossings = netlist.get_clock_crossings();
+
+        // ff_c shares the clock of ff_a, and div only drives the clock port of ff_b
+        assert_eq!(crossings.len(), 1);
+        let info = netlist.get_clock_crossing_info(&crossings[0]);
+        assert_eq!(
+            (info.from_cell.as_str(), info.to_cell.as_str()),
+            ("ff_a", "ff_b")
+        );
+        assert_eq!(
+            (info.from_clock.as_str(), info.to_clock.as_str()),
+            ("clk_a", "clk_b")
+        );
+        assert_eq!(info.nets, ["q", "d"]);
+    }
+
+    #[test]
+    fn ignores_ice40_logic_cells_without_dff() {
+        let design = json!({ "modules": { "top": {
+            "cells": {
+                "lc": cell("ICESTORM_LC", "X1/Y1/lc0", &[("CLK", "input", 1), ("O", "output", 2)]),
+            },
+            "netnames": {
+                "clk": { "bits": [1], "attributes": { "ROUTING": "" } },
+                "o": { "bits": [2], "attributes": { "ROUTING": "" } },
+            },
+        } } });
+        let netlist = Netlist::new(&serde_json::from_value(design).unwrap());
+
+        assert!(!netlist.cells[0].is_sequential());
+        assert!(netlist.get_clock_domains().is_empty());
+    }
+}
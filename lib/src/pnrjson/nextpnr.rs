@@ -1,7 +1,9 @@
 use anyhow::{Error, Result};
+use rustc_hash::FxHashMap;
 
 use crate::architecture::{Wire, WireLocation};
 use crate::pnrjson::nextpnr_types::Netname;
+use crate::pnrjson::routing::RoutingTree;
 use crate::pnrjson::{Chip, INextpnrJSON, NextpnrJson};
 
 pub struct NextpnrBel<'a> {
@@ -31,6 +33,22 @@ pub struct PipFromTo {
     pub name: String,
 }
 
+impl PipFromTo {
+    /// Name of the wire driving this pip, in the same `X{x}/Y{y}/{name}` form as `ROUTING` wires.
+    /// On Nexus this is the pip's tile wire, which only names the node if it is its primary wire.
+    pub fn src_wire_id(&self, chip: &Chip) -> String {
+        let (x, y) = match chip {
+            // ECP5, MachXO2 and Nexus pip wires are relative to the pip location
+            Chip::ECP5 | Chip::MachXO2 | Chip::Nexus => (
+                self.location.x + self.from.location.x,
+                self.location.y + self.from.location.y,
+            ),
+            Chip::ICE40 => (self.from.location.x, self.from.location.y),
+        };
+        format!("X{}/Y{}/{}", x, y, self.from.name)
+    }
+}
+
 fn parse_wire(s: String, delimiter: &str) -> Option<Wire> {
     let parts: Vec<_> = s.splitn(3, delimiter).collect();
     Some(Wire {
@@ -55,7 +73,21 @@ fn parse_pip_from_to(s: String, chip: &Chip) -> Option<PipFromTo> {
 
     let (pip_delim, wire_delim) = match chip {
         Chip::ICE40 => (".->.", "."),
-        Chip::ECP5 => ("->", "_"),
+        Chip::ECP5 | Chip::MachXO2 => ("->", "_"),
+        // `PIP/{from}/{to}`, both tile wires of the pip's own tile
+        Chip::Nexus => {
+            let (from, to) = parts.get(2)?.strip_prefix("PIP/")?.split_once('/')?;
+            let tile_wire = |name: &str| Wire {
+                location: WireLocation { x: 0, y: 0 },
+                name: name.to_string(),
+            };
+            return Some(PipFromTo {
+                location: WireLocation { x, y },
+                from: tile_wire(from),
+                to: tile_wire(to),
+                name: s,
+            });
+        }
     };
 
     let pip_parts: Vec<_> = parts.get(2)?.splitn(2, pip_delim).collect();
@@ -83,6 +115,41 @@ impl Netname {
             })
             .collect()
     }
+
+    /// Routing tree of the net, joining pips to their driver through `pip_sources` where given.
+    pub fn get_routing_tree(
+        &self,
+        name: &str,
+        chip: &Chip,
+        pip_sources: &FxHashMap<String, String>,
+    ) -> RoutingTree {
+        let parts: Vec<&str> = self.attributes.ROUTING.split(';').collect();
+        let parts = parts
+            .chunks_exact(3)
+            .map(|c| {
+                (
+                    c[0].to_string(),
+                    parse_pip_from_to(c[1].to_string(), chip),
+                    c[2].parse().unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        RoutingTree::new(name.to_string(), parts, |pip| {
+            pip_sources
+                .get(&pip.name)
+                .cloned()
+                .unwrap_or_else(|| pip.src_wire_id(chip))
+        })
+    }
+
+    pub fn get_wires(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .ROUTING
+            .split(';')
+            .step_by(3)
+            .filter(|w| !w.is_empty())
+    }
 }
 
 impl NextpnrJson {
@@ -130,3 +197,99 @@ impl NextpnrJson {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pnrjson::nextpnr_types::NetnameAttributes;
+    use itertools::Itertools;
+
+    fn netname(routing: &[(&str, &str)]) -> Netname {
+        Netname {
+            bits: vec![],
+            attributes: NetnameAttributes {
+                ROUTING: routing
+                    .iter()
+                    .map(|(wire, pip)| format!("{wire};{pip};1"))
+                    .join(";"),
+            },
+        }
+    }
+
+    fn tree_wires(tree: &RoutingTree, sink: &str) -> Vec<String> {
+        tree.path_to(tree.find_wire(sink).unwrap())
+            .into_iter()
+            .map(|n| tree.nodes[n].wire.clone())
+            .collect()
+    }
+
+    #[test]
+    fn joins_ecp5_pips_through_their_relative_source() {
+        let net = netname(&[
+            ("X5/Y7/Q0", ""),
+            ("X5/Y7/H02E0001", "X5/Y7/0_0_Q0->0_0_H02E0001"),
+            ("X6/Y7/A1", "X7/Y7/-1_0_H02W0101->-1_0_A1"),
+        ]);
+
+        let routing = net.get_routing(&Chip::ECP5);
+        assert_eq!(routing.len(), 2);
+        assert_eq!(routing[1].pip.src_wire_id(&Chip::ECP5), "X6/Y7/H02W0101");
+
+        // H02E0001 of X5/Y7 is H02W0101 of X6/Y7 only to the chipdb, so A1 is a separate root
+        let tree = net.get_routing_tree("net", &Chip::ECP5, &FxHashMap::default());
+        assert_eq!(
+            tree_wires(&tree, "X5/Y7/H02E0001"),
+            ["X5/Y7/Q0", "X5/Y7/H02E0001"]
+        );
+        assert_eq!(tree_wires(&tree, "X6/Y7/A1"), ["X6/Y7/A1"]);
+    }
+
+    #[test]
+    fn joins_ice40_pips_through_their_absolute_source() {
+        let net = netname(&[
+            ("X1/Y1/lutff_0/out", ""),
+            (
+                "X1/Y1/local_g0_0",
+                "X1/Y1/1.1.lutff_0/out.->.1.1.local_g0_0",
+            ),
+        ]);
+
+        let tree = net.get_routing_tree("net", &Chip::ICE40, &FxHashMap::default());
+        assert_eq!(
+            tree_wires(&tree, "X1/Y1/local_g0_0"),
+            ["X1/Y1/lutff_0/out", "X1/Y1/local_g0_0"]
+        );
+    }
+
+    #[test]
+    fn joins_nexus_pips_through_their_resolved_node() {
+        let net = netname(&[
+            ("X3/Y4/JQ0_R4C3", ""),
+            ("X3/Y4/HPBX0000", "X3/Y4/PIP/JQ0/HPBX0000"),
+            ("X5/Y4/JA0", "X5/Y4/PIP/HPBX0002/JA0"),
+        ]);
+
+        let routing = net.get_routing(&Chip::Nexus);
+        assert_eq!(routing[1].pip.src_wire_id(&Chip::Nexus), "X5/Y4/HPBX0002");
+
+        // HPBX0002 of X5/Y4 is a non-primary wire of the HPBX0000 node
+        let pip_sources = FxHashMap::from_iter([
+            (
+                "X3/Y4/PIP/JQ0/HPBX0000".to_string(),
+                "X3/Y4/JQ0_R4C3".to_string(),
+            ),
+            (
+                "X5/Y4/PIP/HPBX0002/JA0".to_string(),
+                "X3/Y4/HPBX0000".to_string(),
+            ),
+        ]);
+        let tree = net.get_routing_tree("net", &Chip::Nexus, &pip_sources);
+        assert_eq!(
+            tree_wires(&tree, "X5/Y4/JA0"),
+            ["X3/Y4/JQ0_R4C3", "X3/Y4/HPBX0000", "X5/Y4/JA0"]
+        );
+
+        let tree = net.get_routing_tree("net", &Chip::Nexus, &FxHashMap::default());
+        assert_eq!(tree_wires(&tree, "X5/Y4/JA0"), ["X5/Y4/JA0"]);
+    }
+}
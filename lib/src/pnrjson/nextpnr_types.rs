@@ -10,11 +10,36 @@ use wasm_bindgen::prelude::*;
 pub struct CellAttributes {
     pub NEXTPNR_BEL: String,
     pub cellType: Option<String>,
+    pub IO_TYPE: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PortDirection {
+    Input,
+    Output,
+    Inout,
+}
+
+/// A single bit of a port or net: either a net number or a constant (`"0"`, `"1"`, `"x"`, `"z"`).
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Bit {
+    Net(u32),
+    Const(#[allow(dead_code)] String),
 }
 
 #[derive(Deserialize)]
 pub struct Cell {
+    #[serde(default)]
+    pub r#type: String,
+    #[serde(default)]
+    pub parameters: FxHashMap<String, String>,
     pub attributes: CellAttributes,
+    #[serde(default)]
+    pub port_directions: FxHashMap<String, PortDirection>,
+    #[serde(default)]
+    pub connections: FxHashMap<String, Vec<Bit>>,
 }
 
 #[derive(Deserialize)]
@@ -24,6 +49,8 @@ pub struct NetnameAttributes {
 
 #[derive(Deserialize)]
 pub struct Netname {
+    #[serde(default)]
+    pub bits: Vec<Bit>,
     pub attributes: NetnameAttributes,
 }
 
@@ -50,10 +77,15 @@ const INEXTPNR_JSON: &'static str = r#"
 interface CellAttributes {
     NEXTPNR_BEL: string,
     cellType?: string,
+    IO_TYPE?: string,
 }
 
 interface Cell {
+    type?: string,
+    parameters?: Record<string, string>,
     attributes: CellAttributes,
+    port_directions?: Record<string, "input" | "output" | "inout">,
+    connections?: Record<string, (number | string)[]>,
 }
 
 interface NetnameAttributes {
@@ -61,6 +93,7 @@ interface NetnameAttributes {
 }
 
 interface Netname {
+    bits?: (number | string)[],
     attributes: NetnameAttributes,
 }
 
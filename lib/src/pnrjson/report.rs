@@ -1,7 +1,47 @@
 use anyhow::{Error, Result};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
 
+use crate::pnrjson::report_types::Cell;
 use crate::pnrjson::{IReportJSON, ReportJson};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum PathStep {
+    Next,
+    Prev,
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const IPATH_SEGMENT_INFO: &'static str = r#"
+interface PathSegmentInfo {
+    path: number,
+    segment: number,
+    segments: number,
+    type: string,
+    delay: number,
+    cumulative_delay: number,
+    from: Cell,
+    to: Cell,
+    net?: string,
+}
+"#;
+
+#[derive(Serialize)]
+pub struct PathSegmentInfo {
+    pub path: usize,
+    pub segment: usize,
+    pub segments: usize,
+    pub r#type: String,
+    pub delay: f32,
+    /// Delay from the start of the path up to and including this segment.
+    pub cumulative_delay: f32,
+    pub from: Cell,
+    pub to: Cell,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+}
+
 impl ReportJson {
     pub fn from_jsobj(val: IReportJSON) -> Result<Self> {
         match serde_wasm_bindgen::from_value(val.into()) {
@@ -16,4 +56,21 @@ impl ReportJson {
             .flat_map(|s| s.path.iter().filter_map(|s| s.net.as_ref()))
             .collect()
     }
+
+    pub fn get_path_segment(&self, path: usize, segment: usize) -> Option<PathSegmentInfo> {
+        let segments = &self.critical_paths.get(path)?.path;
+        let seg = segments.get(segment)?;
+
+        Some(PathSegmentInfo {
+            path,
+            segment,
+            segments: segments.len(),
+            r#type: seg.r#type.clone(),
+            delay: seg.delay,
+            cumulative_delay: segments[..=segment].iter().map(|s| s.delay).sum(),
+            from: seg.from.clone(),
+            to: seg.to.clone(),
+            net: seg.net.clone(),
+        })
+    }
 }
@@ -1,24 +1,30 @@
 #![allow(non_snake_case)]
 
-use serde::Deserialize;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 // === RUST ===
 
-// #[derive(Deserialize)]
-// pub struct Cell {
-//     pub cell: String,
-//     pub loc: (i32, i32),
-//     pub port: String,
-// }
+#[derive(Deserialize, Serialize, Clone, Default)]
+pub struct Cell {
+    pub cell: String,
+    pub loc: (i32, i32),
+    pub port: String,
+}
 
 #[derive(Deserialize)]
 pub struct CriticalPathSegment {
-    // pub delay: f32,
-    // pub from: Cell,
-    // pub to: Cell,
+    #[serde(default)]
+    pub delay: f32,
+    #[serde(default)]
+    pub from: Cell,
+    #[serde(default)]
+    pub to: Cell,
     pub net: Option<String>,
-    // pub r#type: String,
+    /// One of `clk-to-q`, `source`, `logic`, `routing` or `setup`.
+    #[serde(default)]
+    pub r#type: String,
 }
 
 #[derive(Deserialize)]
@@ -28,9 +34,25 @@ pub struct CriticalPath {
     // pub to: String,
 }
 
+#[derive(Deserialize, Serialize, Clone)]
+pub struct Utilization {
+    pub available: u32,
+    pub used: u32,
+}
+
+#[derive(Deserialize, Serialize, Clone)]
+pub struct Fmax {
+    pub achieved: f32,
+    pub constraint: f32,
+}
+
 #[derive(Deserialize)]
 pub struct ReportJson {
     pub critical_paths: Vec<CriticalPath>,
+    #[serde(default)]
+    pub fmax: FxHashMap<String, Fmax>,
+    #[serde(default)]
+    pub utilization: FxHashMap<String, Utilization>,
 }
 
 // === TYPESCRIPT ===
@@ -47,6 +69,7 @@ interface CriticalPathSegment {
     delay: number;
     from: Cell;
     to: Cell;
+    net?: string;
     type: string;
 }
 
@@ -56,8 +79,20 @@ interface CriticalPath {
     to: string;
 }
 
+interface Utilization {
+    available: number;
+    used: number;
+}
+
+interface Fmax {
+    achieved: number;
+    constraint: number;
+}
+
 interface ReportJson {
     critical_paths: CriticalPath[],
+    fmax?: Record<string, Fmax>,
+    utilization?: Record<string, Utilization>,
 }
 "#;
 
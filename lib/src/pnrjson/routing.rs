@@ -0,0 +1,401 @@
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::pnrjson::nextpnr::PipFromTo;
+
+pub type RouteNodeIndex = usize;
+
+#[wasm_bindgen(typescript_custom_section)]
+const IROUTING: &'static str = r#"
+interface RouteStep {
+    wire: string,
+    pip?: string,
+    strength: number,
+    depth: number,
+}
+
+interface RouteSink {
+    wire: string,
+    path: RouteStep[],
+}
+
+interface RouteInfo {
+    net: string,
+    sinks: RouteSink[],
+}
+
+interface NetDetour {
+    net: string,
+    sink: string,
+    routed_length: number,
+    manhattan: number,
+    ratio: number,
+}
+"#;
+
+/// One wire of a routed net, together with the pip that drives it.
+pub struct RouteNode {
+    pub wire: String,
+    pub pip: Option<PipFromTo>,
+    /// nextpnr `PlaceStrength` of the binding (e.g. 1 = weak, 5 = locked).
+    pub strength: u8,
+    pub parent: Option<RouteNodeIndex>,
+    pub children: Vec<RouteNodeIndex>,
+    /// Number of pips between the driver and this wire.
+    pub depth: u32,
+}
+
+/// The directed driver→sinks tree of a net, rebuilt from its `ROUTING` attribute.
+pub struct RoutingTree {
+    pub net: String,
+    pub nodes: Vec<RouteNode>,
+}
+
+#[derive(Serialize)]
+pub struct RouteStep {
+    pub wire: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pip: Option<String>,
+    pub strength: u8,
+    pub depth: u32,
+}
+
+#[derive(Serialize)]
+pub struct RouteSink {
+    pub wire: String,
+    pub path: Vec<RouteStep>,
+}
+
+#[derive(Serialize)]
+pub struct RouteInfo {
+    pub net: String,
+    pub sinks: Vec<RouteSink>,
+}
+
+/// How far the route to a net's worst sink strays from a direct route, in tile hops.
+#[derive(Serialize)]
+pub struct NetDetour {
+    pub net: String,
+    pub sink: String,
+    pub routed_length: u32,
+    pub manhattan: u32,
+    pub ratio: f32,
+}
+
+/// Tile of an `X<x>/Y<y>/...` wire or BEL name.
+pub fn grid_location(name: &str) -> Option<(i32, i32)> {
+    let mut parts = name.splitn(3, '/');
+    let x = parts.next()?.strip_prefix('X')?.parse().ok()?;
+    let y = parts.next()?.strip_prefix('Y')?.parse().ok()?;
+    Some((x, y))
+}
+
+fn manhattan(a: Option<(i32, i32)>, b: Option<(i32, i32)>) -> u32 {
+    match (a, b) {
+        (Some((ax, ay)), Some((bx, by))) => ax.abs_diff(bx) + ay.abs_diff(by),
+        _ => 0,
+    }
+}
+
+fn tile_distance(a: &str, b: &str) -> u32 {
+    manhattan(grid_location(a), grid_location(b))
+}
+
+impl RoutingTree {
+    /// Builds the tree from `(wire, pip, strength)` entries, where `src_wire` is the wire the
+    /// pip is driven from. Wires whose source is not part of the net become extra roots.
+    pub fn new(
+        net: String,
+        parts: Vec<(String, Option<PipFromTo>, u8)>,
+        src_wire: impl Fn(&PipFromTo) -> String,
+    ) -> Self {
+        let by_wire: FxHashMap<String, RouteNodeIndex> = parts
+            .iter()
+            .enumerate()
+            .map(|(i, (wire, _, _))| (wire.clone(), i))
+            .collect();
+
+        let mut nodes: Vec<RouteNode> = parts
+            .into_iter()
+            .map(|(wire, pip, strength)| RouteNode {
+                wire,
+                pip,
+                strength,
+                parent: None,
+                children: vec![],
+                depth: 0,
+            })
+            .collect();
+
+        let mut roots = vec![];
+        for i in 0..nodes.len() {
+            let parent = nodes[i]
+                .pip
+                .as_ref()
+                .and_then(|pip| by_wire.get(&src_wire(pip)).copied())
+                .filter(|&p| p != i);
+
+            match parent {
+                Some(p) => {
+                    nodes[i].parent = Some(p);
+                    nodes[p].children.push(i);
+                }
+                None => roots.push(i),
+            }
+        }
+
+        // Assign depths top-down, this also guards against cycles in malformed input
+        let mut stack: Vec<(RouteNodeIndex, u32)> = roots.iter().map(|&r| (r, 0)).collect();
+        let mut visited = vec![false; nodes.len()];
+        while let Some((i, depth)) = stack.pop() {
+            if std::mem::replace(&mut visited[i], true) {
+                continue;
+            }
+            nodes[i].depth = depth;
+            stack.extend(nodes[i].children.iter().map(|&c| (c, depth + 1)));
+        }
+
+        Self { net, nodes }
+    }
+
+    pub fn find_wire(&self, wire: &str) -> Option<RouteNodeIndex> {
+        self.nodes.iter().position(|n| n.wire == wire)
+    }
+
+    /// Wires that do not drive any other wire of the net.
+    pub fn sinks(&self) -> impl Iterator<Item = RouteNodeIndex> + '_ {
+        (0..self.nodes.len()).filter(|&i| self.nodes[i].children.is_empty())
+    }
+
+    /// Nodes from the driver down to `node`, inclusive.
+    pub fn path_to(&self, node: RouteNodeIndex) -> Vec<RouteNodeIndex> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(parent) = self.nodes[current].parent {
+            if path.len() > self.nodes.len() {
+                break;
+            }
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Compares the tile hops along the path to each sink with the Manhattan distance between
+    /// the driver and that sink, and returns the sink with the largest ratio. The distance is
+    /// measured between the driver and sink cells where `driver` and `sink_location` know
+    /// them, as wire names can be relative to another tile than the BEL, and between the wires
+    /// otherwise.
+    pub fn get_worst_detour(
+        &self,
+        driver: Option<(i32, i32)>,
+        sink_location: impl Fn(&str) -> Option<(i32, i32)>,
+    ) -> Option<NetDetour> {
+        self.sinks()
+            .filter_map(|sink| {
+                let path = self.path_to(sink);
+                let driver_wire = &self.nodes[*path.first()?].wire;
+                let sink = &self.nodes[sink].wire;
+
+                let routed_length = path
+                    .iter()
+                    .tuple_windows()
+                    .map(|(&a, &b)| tile_distance(&self.nodes[a].wire, &self.nodes[b].wire))
+                    .sum();
+                let manhattan = manhattan(
+                    driver.or_else(|| grid_location(driver_wire)),
+                    sink_location(sink).or_else(|| grid_location(sink)),
+                );
+
+                Some(NetDetour {
+                    net: self.net.clone(),
+                    sink: sink.clone(),
+                    routed_length,
+                    manhattan,
+                    ratio: routed_length as f32 / manhattan.max(1) as f32,
+                })
+            })
+            .max_by(|a, b| a.ratio.total_cmp(&b.ratio))
+    }
+
+    pub fn to_route_info(&self) -> RouteInfo {
+        let sinks = self
+            .sinks()
+            .map(|sink| RouteSink {
+                wire: self.nodes[sink].wire.clone(),
+                path: self
+                    .path_to(sink)
+                    .into_iter()
+                    .map(|i| {
+                        let node = &self.nodes[i];
+                        RouteStep {
+                            wire: node.wire.clone(),
+                            pip: node.pip.as_ref().map(|p| p.name.clone()),
+                            strength: node.strength,
+                            depth: node.depth,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        RouteInfo {
+            net: self.net.clone(),
+            sinks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::architecture::{Wire, WireLocation};
+
+    /// An iCE40 style pip between two absolute wires.
+    fn pip(from: &str, to: &str) -> PipFromTo {
+        let wire = |name: &str| {
+            let (x, y) = grid_location(name).unwrap();
+            Wire {
+                location: WireLocation {
+                    x: x as i16,
+                    y: y as i16,
+                },
+                name: name.splitn(3, '/').nth(2).unwrap().to_string(),
+            }
+        };
+        PipFromTo {
+            location: WireLocation { x: 0, y: 0 },
+            from: wire(from),
+            to: wire(to),
+            name: format!("{from}->{to}"),
+        }
+    }
+
+    fn src_wire(pip: &PipFromTo) -> String {
+        format!(
+            "X{}/Y{}/{}",
+            pip.from.location.x, pip.from.location.y, pip.from.name
+        )
+    }
+
+    /// A net driven at `X0/Y0/A` forking at `X1/Y0/B` into `X1/Y2/C` and `X3/Y0/D`.
+    fn tree() -> RoutingTree {
+        let parts = vec![
+            ("X1/Y2/C".to_string(), Some(pip("X1/Y0/B", "X1/Y2/C")), 1),
+            ("X0/Y0/A".to_string(), None, 1),
+            ("X3/Y0/D".to_string(), Some(pip("X1/Y0/B", "X3/Y0/D")), 5),
+            ("X1/Y0/B".to_string(), Some(pip("X0/Y0/A", "X1/Y0/B")), 1),
+        ];
+        RoutingTree::new("net".to_string(), parts, src_wire)
+    }
+
+    fn wires(tree: &RoutingTree, nodes: Vec<RouteNodeIndex>) -> Vec<&str> {
+        nodes
+            .into_iter()
+            .map(|n| tree.nodes[n].wire.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn joins_pips_to_the_wire_driving_them() {
+        let tree = tree();
+        let b = tree.find_wire("X1/Y0/B").unwrap();
+
+        assert_eq!(tree.nodes[b].parent, tree.find_wire("X0/Y0/A"));
+        assert_eq!(
+            wires(&tree, tree.nodes[b].children.clone()),
+            ["X1/Y2/C", "X3/Y0/D"]
+        );
+        let depths: Vec<_> = tree.nodes.iter().map(|n| n.depth).collect();
+        assert_eq!(depths, [2, 0, 2, 1]);
+        assert_eq!(wires(&tree, tree.sinks().collect()), ["X1/Y2/C", "X3/Y0/D"]);
+    }
+
+    #[test]
+    fn walks_from_the_driver_to_a_sink() {
+        let tree = tree();
+        let d = tree.find_wire("X3/Y0/D").unwrap();
+
+        assert_eq!(
+            wires(&tree, tree.path_to(d)),
+            ["X0/Y0/A", "X1/Y0/B", "X3/Y0/D"]
+        );
+
+        let info = tree.to_route_info();
+        let path = &info.sinks[1].path;
+        assert_eq!(path[0].pip, None);
+        assert_eq!(path[2].pip.as_deref(), Some("X1/Y0/B->X3/Y0/D"));
+        assert_eq!(path[2].strength, 5);
+    }
+
+    #[test]
+    fn keeps_wires_with_an_unrouted_source_as_roots() {
+        let parts = vec![
+            ("X0/Y0/A".to_string(), None, 1),
+            ("X2/Y0/C".to_string(), Some(pip("X1/Y0/B", "X2/Y0/C")), 1),
+        ];
+        let tree = RoutingTree::new("net".to_string(), parts, src_wire);
+
+        assert!(tree
+            .nodes
+            .iter()
+            .all(|n| n.parent.is_none() && n.depth == 0));
+        assert_eq!(tree.path_to(1), [1]);
+    }
+
+    #[test]
+    fn survives_cycles_in_malformed_routing() {
+        let parts = vec![
+            ("X0/Y0/A".to_string(), None, 1),
+            ("X1/Y0/B".to_string(), Some(pip("X2/Y0/C", "X1/Y0/B")), 1),
+            ("X2/Y0/C".to_string(), Some(pip("X1/Y0/B", "X2/Y0/C")), 1),
+        ];
+        let tree = RoutingTree::new("net".to_string(), parts, src_wire);
+
+        assert!(tree.path_to(1).len() <= tree.nodes.len() + 1);
+        assert_eq!(tree.sinks().count(), 1);
+    }
+
+    /// A net driven at `X0/Y0/A` with a direct sink `X1/Y0/E` and a sink `X0/Y2/D` reached
+    /// around a loop through `X2/Y0/B` and `X2/Y2/C`.
+    fn detour() -> RoutingTree {
+        let parts = vec![
+            ("X0/Y0/A".to_string(), None, 1),
+            ("X1/Y0/E".to_string(), Some(pip("X0/Y0/A", "X1/Y0/E")), 1),
+            ("X2/Y0/B".to_string(), Some(pip("X0/Y0/A", "X2/Y0/B")), 1),
+            ("X2/Y2/C".to_string(), Some(pip("X2/Y0/B", "X2/Y2/C")), 1),
+            ("X0/Y2/D".to_string(), Some(pip("X2/Y2/C", "X0/Y2/D")), 1),
+        ];
+        RoutingTree::new("net".to_string(), parts, src_wire)
+    }
+
+    #[test]
+    fn finds_the_sink_with_the_largest_detour() {
+        let worst = detour().get_worst_detour(None, |_| None).unwrap();
+
+        assert_eq!(worst.sink, "X0/Y2/D");
+        assert_eq!((worst.routed_length, worst.manhattan), (6, 2));
+        assert_eq!(worst.ratio, 3.0);
+    }
+
+    #[test]
+    fn measures_detours_between_the_driver_and_sink_cells() {
+        let sink_location = |wire: &str| (wire == "X1/Y0/E").then_some((5, 0));
+        let worst = detour()
+            .get_worst_detour(Some((0, 2)), sink_location)
+            .unwrap();
+
+        // D is next to the driver cell, so its detour outweighs the far sink E
+        assert_eq!(worst.sink, "X0/Y2/D");
+        assert_eq!((worst.routed_length, worst.manhattan), (6, 0));
+        assert_eq!(worst.ratio, 6.0);
+
+        let sink_location = |wire: &str| (wire == "X0/Y2/D").then_some((2, 2));
+        let worst = detour().get_worst_detour(None, sink_location).unwrap();
+        assert_eq!(worst.sink, "X0/Y2/D");
+        assert_eq!(worst.manhattan, 4);
+    }
+}
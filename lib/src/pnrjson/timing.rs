@@ -0,0 +1,374 @@
+use itertools::Itertools;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::pnrjson::netlist::{CellIndex, NetIndex, Netlist, NetlistCell};
+use crate::pnrjson::report_types::{Cell, CriticalPath};
+
+#[wasm_bindgen(typescript_custom_section)]
+const ITIMING: &'static str = r#"
+interface EstimatedSegment {
+    type: string,
+    delay: number,
+    report_delay?: number,
+    from: Cell,
+    to: Cell,
+    net?: string,
+    estimated?: boolean,
+}
+
+interface EstimatedPath {
+    delay: number,
+    report_delay?: number,
+    segments: EstimatedSegment[],
+}
+"#;
+
+/// Routing delay from the driver of a net to one of its sink pins, in nanoseconds.
+#[derive(Clone, Copy)]
+pub struct PinDelay {
+    pub delay: f32,
+    /// The sink pin could not be traced to a routed wire and `delay` is the slowest sink of the
+    /// net instead.
+    pub estimated: bool,
+}
+
+/// Delays of the placed and routed design, in nanoseconds.
+pub trait DelayModel {
+    /// Routing delay from the driver of `net` to `port` of the `sink` cell.
+    fn net_delay(&self, net: NetIndex, sink: CellIndex, port: &str) -> PinDelay;
+    fn cell_delay(&self, cell: &NetlistCell, from_port: &str, to_port: &str) -> Option<f32>;
+    fn setup_time(&self, cell: &NetlistCell, port: &str, clock_port: &str) -> Option<f32>;
+}
+
+/// A segment of a timing path, shaped like the critical path segments of nextpnr's report.
+#[derive(Serialize)]
+pub struct EstimatedSegment {
+    /// One of `clk-to-q`, `source`, `logic`, `routing` or `setup`.
+    pub r#type: String,
+    pub delay: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_delay: Option<f32>,
+    pub from: Cell,
+    pub to: Cell,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+    /// Set on routing segments whose delay is not that of the sink pin, see `PinDelay`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub estimated: bool,
+}
+
+#[derive(Serialize)]
+pub struct EstimatedPath {
+    pub delay: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_delay: Option<f32>,
+    pub segments: Vec<EstimatedSegment>,
+}
+
+/// How the arrival time of a net was reached.
+#[derive(Clone, Copy)]
+enum Arrival {
+    /// Launched by a sequential cell, after its clock-to-output delay.
+    Launch(f32),
+    /// Driven by nothing, or by a cell without inputs.
+    Source,
+    /// Through a combinational cell from one of its inputs.
+    Logic {
+        port: usize,
+        net: NetIndex,
+        delay: f32,
+    },
+}
+
+fn cell_ref(cell: &NetlistCell, port: &str) -> Cell {
+    let mut parts = cell.bel.splitn(3, '/');
+    let mut coord = |prefix: char| {
+        parts
+            .next()
+            .and_then(|p| p.strip_prefix(prefix))
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(-1)
+    };
+    let x = coord('X');
+    let y = coord('Y');
+
+    Cell {
+        cell: cell.name.clone(),
+        loc: (x, y),
+        port: port.to_string(),
+    }
+}
+
+struct Analysis<'n, M: DelayModel> {
+    netlist: &'n Netlist,
+    model: &'n M,
+    /// Arrival time at each net's driver, `None` until computed.
+    arrivals: Vec<Option<(f32, Arrival)>>,
+    visiting: Vec<bool>,
+}
+
+fn output_port(netlist: &Netlist, cell: CellIndex, net: NetIndex) -> &str {
+    netlist.cells[cell]
+        .outputs
+        .iter()
+        .find(|&&(_, n)| n == net)
+        .map_or("", |(port, _)| port.as_str())
+}
+
+impl<M: DelayModel> Analysis<'_, M> {
+    fn arrival(&mut self, net: NetIndex) -> f32 {
+        if let Some((time, _)) = self.arrivals[net] {
+            return time;
+        }
+        // Break combinational loops
+        if std::mem::replace(&mut self.visiting[net], true) {
+            return 0.0;
+        }
+
+        let netlist = self.netlist;
+        let result = match &netlist.nets[net].driver {
+            None => (0.0, Arrival::Source),
+            Some(driver) => {
+                let cell = &netlist.cells[driver.cell];
+                let out_port = output_port(netlist, driver.cell, net);
+
+                if cell.is_sequential() {
+                    let delay = cell
+                        .clock_port()
+                        .and_then(|clk| self.model.cell_delay(cell, clk, out_port))
+                        .unwrap_or(0.0);
+                    (delay, Arrival::Launch(delay))
+                } else {
+                    let mut best = (0.0, Arrival::Source);
+                    for (port, &(ref name, in_net)) in cell.inputs.iter().enumerate() {
+                        let delay = self.model.cell_delay(cell, name, out_port).unwrap_or(0.0);
+                        let time = self.arrival(in_net)
+                            + self.model.net_delay(in_net, driver.cell, name).delay
+                            + delay;
+                        if matches!(best.1, Arrival::Source) || time > best.0 {
+                            best = (
+                                time,
+                                Arrival::Logic {
+                                    port,
+                                    net: in_net,
+                                    delay,
+                                },
+                            );
+                        }
+                    }
+                    best
+                }
+            }
+        };
+
+        self.arrivals[net] = Some(result);
+        result.0
+    }
+
+    /// Segments from the start of the path up to the driver of `net`.
+    fn trace(&self, mut net: NetIndex) -> Vec<EstimatedSegment> {
+        let mut segments = vec![];
+        for _ in 0..self.netlist.nets.len() {
+            let Some(driver) = &self.netlist.nets[net].driver else {
+                break;
+            };
+            let cell = &self.netlist.cells[driver.cell];
+            let out = cell_ref(cell, output_port(self.netlist, driver.cell, net));
+
+            match self.arrivals[net].map(|(_, a)| a) {
+                Some(Arrival::Logic {
+                    port,
+                    net: in_net,
+                    delay,
+                }) => {
+                    segments.push(EstimatedSegment {
+                        r#type: "logic".to_string(),
+                        delay,
+                        report_delay: None,
+                        from: cell_ref(cell, &cell.inputs[port].0),
+                        to: out,
+                        net: None,
+                        estimated: false,
+                    });
+                    segments.push(self.routing_segment(in_net, driver.cell, &cell.inputs[port].0));
+                    net = in_net;
+                }
+                Some(Arrival::Launch(delay)) => {
+                    segments.push(EstimatedSegment {
+                        r#type: "clk-to-q".to_string(),
+                        delay,
+                        report_delay: None,
+                        from: out.clone(),
+                        to: out,
+                        net: None,
+                        estimated: false,
+                    });
+                    break;
+                }
+                _ => {
+                    segments.push(EstimatedSegment {
+                        r#type: "source".to_string(),
+                        delay: 0.0,
+                        report_delay: None,
+                        from: out.clone(),
+                        to: out,
+                        net: None,
+                        estimated: false,
+                    });
+                    break;
+                }
+            }
+        }
+
+        segments.reverse();
+        segments
+    }
+
+    fn routing_segment(&self, net: NetIndex, sink: CellIndex, port: &str) -> EstimatedSegment {
+        let driver = self.netlist.nets[net].driver.as_ref();
+        let from = match driver {
+            Some(d) => cell_ref(
+                &self.netlist.cells[d.cell],
+                output_port(self.netlist, d.cell, net),
+            ),
+            None => cell_ref(&self.netlist.cells[sink], ""),
+        };
+
+        let delay = self.model.net_delay(net, sink, port);
+        EstimatedSegment {
+            r#type: "routing".to_string(),
+            delay: delay.delay,
+            report_delay: None,
+            from,
+            to: cell_ref(&self.netlist.cells[sink], port),
+            net: Some(self.netlist.nets[net].name.clone()),
+            estimated: delay.estimated,
+        }
+    }
+}
+
+impl Netlist {
+    /// Estimates the arrival time at every timing endpoint (the data inputs of sequential cells
+    /// and the inputs of cells without outputs) and returns the `count` slowest paths, one per
+    /// endpoint cell. Arcs missing from the delay model count as zero delay.
+    pub fn estimate_paths(&self, model: &impl DelayModel, count: usize) -> Vec<EstimatedPath> {
+        let mut analysis = Analysis {
+            netlist: self,
+            model,
+            arrivals: vec![None; self.nets.len()],
+            visiting: vec![false; self.nets.len()],
+        };
+
+        let mut endpoints = vec![];
+        for (index, cell) in self.cells.iter().enumerate() {
+            let is_endpoint = cell.is_sequential() || cell.outputs.is_empty();
+            if !is_endpoint {
+                continue;
+            }
+            let clock = cell.clock_port();
+
+            let worst = cell
+                .inputs
+                .iter()
+                .filter(|(port, _)| !cell.is_clock_port(port))
+                .map(|(port, net)| {
+                    let setup = clock
+                        .and_then(|clk| model.setup_time(cell, port, clk))
+                        .unwrap_or(0.0);
+                    let time =
+                        analysis.arrival(*net) + model.net_delay(*net, index, port).delay + setup;
+                    (time, port, *net, setup)
+                })
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some(worst) = worst {
+                endpoints.push((index, worst));
+            }
+        }
+
+        endpoints
+            .into_iter()
+            .sorted_by(|(_, a), (_, b)| b.0.total_cmp(&a.0))
+            .take(count)
+            .map(|(index, (time, port, net, setup))| {
+                let cell = &self.cells[index];
+                let mut segments = analysis.trace(net);
+                segments.push(analysis.routing_segment(net, index, port));
+                segments.push(EstimatedSegment {
+                    r#type: "setup".to_string(),
+                    delay: setup,
+                    report_delay: None,
+                    from: cell_ref(cell, port),
+                    to: cell_ref(cell, port),
+                    net: None,
+                    estimated: false,
+                });
+
+                EstimatedPath {
+                    delay: time,
+                    report_delay: None,
+                    segments,
+                }
+            })
+            .collect()
+    }
+
+    /// Re-estimates every segment of a critical path from nextpnr's report.
+    pub fn estimate_report_path(
+        &self,
+        model: &impl DelayModel,
+        path: &CriticalPath,
+    ) -> EstimatedPath {
+        let segments: Vec<EstimatedSegment> = path
+            .path
+            .iter()
+            .map(|seg| {
+                let from = self.find_cell(&seg.from.cell).map(|c| &self.cells[c]);
+                let to_index = self.find_cell(&seg.to.cell);
+                let to = to_index.map(|c| &self.cells[c]);
+
+                let mut estimated = false;
+                let delay = match seg.r#type.as_str() {
+                    "clk-to-q" => from.and_then(|cell| {
+                        let clk = cell.clock_port()?;
+                        model.cell_delay(cell, clk, &seg.to.port)
+                    }),
+                    "logic" => {
+                        to.and_then(|cell| model.cell_delay(cell, &seg.from.port, &seg.to.port))
+                    }
+                    "routing" => seg
+                        .net
+                        .as_deref()
+                        .and_then(|n| self.find_net(n))
+                        .zip(to_index)
+                        .map(|(net, sink)| {
+                            let delay = model.net_delay(net, sink, &seg.to.port);
+                            estimated = delay.estimated;
+                            delay.delay
+                        }),
+                    "setup" => to.and_then(|cell| {
+                        let clk = cell.clock_port()?;
+                        model.setup_time(cell, &seg.to.port, clk)
+                    }),
+                    _ => None,
+                };
+
+                EstimatedSegment {
+                    r#type: seg.r#type.clone(),
+                    delay: delay.unwrap_or(0.0),
+                    report_delay: Some(seg.delay),
+                    from: seg.from.clone(),
+                    to: seg.to.clone(),
+                    net: seg.net.clone(),
+                    estimated,
+                }
+            })
+            .collect();
+
+        EstimatedPath {
+            delay: segments.iter().map(|s| s.delay).sum(),
+            report_delay: Some(path.path.iter().map(|s| s.delay).sum()),
+            segments,
+        }
+    }
+}
@@ -6,17 +6,26 @@ use rstar::{
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
-use wasm_bindgen::{JsCast, JsValue};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
 use web_sys::js_sys::{Object, Reflect};
 use web_sys::{OffscreenCanvas, WebGl2RenderingContext};
 
-use crate::gfx::{Color, GraphicElement, Style, Type};
-use crate::pnrjson::PnrInfo;
+use crate::gfx::{self, Color, GraphicElement, Style, Type};
+use crate::pnrjson::{
+    CellIndex, ClockCrossingInfo, Cone, ConeDirection, DelayModel, EstimatedPath, Fmax, NetDetour,
+    NetIndex, Netlist, NetlistCell, PathSegmentInfo, PathStep, PinDelay, PnrInfo, RouteInfo,
+    RouteNode, RoutingTree, Utilization,
+};
 use crate::utils::debug_log;
 use crate::webgl::{
     ElementType, Line, LineCoords, Rectangle, RectangleCoords, RenderingProgram, WebGlElement,
 };
-use crate::{architecture::Architecture, decal::DecalXY};
+use crate::{
+    architecture::{Architecture, BelPin, ClockWire, PackagePin, ResourceDelay},
+    decal::DecalXY,
+};
 
 type GraphicElementCollection = FxHashMap<String, Vec<GraphicElement>>;
 type GraphicElements = FxHashMap<ElementType, GraphicElementCollection>;
@@ -27,13 +36,99 @@ type RTreeElementIndex = u32;
 type RTreeData = GeomWithData<RTreeRect<[f32; 2]>, RTreeElementIndex>;
 
 type DecalPointer = (ElementType, String);
+/// A pip decal and the wire decal on its far end.
+type PipHop<DecalID> = (DecalXY<DecalID>, DecalXY<DecalID>);
+/// The uphill and downhill hops from a wire.
+type WireHops<DecalID> = (Vec<PipHop<DecalID>>, Vec<PipHop<DecalID>>);
+/// A wire on a searched route and the pip driving it.
+type SearchStep<DecalID> = (Option<DecalXY<DecalID>>, DecalXY<DecalID>);
 struct DecalSelection {
     selected: Option<DecalPointer>,
     highlighted: Option<DecalPointer>,
+    marked: Vec<(Color, Vec<DecalPointer>)>,
 }
 
 const PICK_EPSILON: f32 = 0.0025;
 
+// Number of colors routed pips are binned into when colored by delay
+const DELAY_COLOR_STEPS: usize = 8;
+
+// Colors cycled through when coloring clock domains or IO banks
+const PALETTE: [Color; 6] = [
+    Color {
+        r: 0x8b,
+        g: 0xe9,
+        b: 0xfd,
+    },
+    Color {
+        r: 0xff,
+        g: 0xb8,
+        b: 0x6c,
+    },
+    Color {
+        r: 0xff,
+        g: 0x79,
+        b: 0xc6,
+    },
+    Color {
+        r: 0x50,
+        g: 0xfa,
+        b: 0x7b,
+    },
+    Color {
+        r: 0xf1,
+        g: 0xfa,
+        b: 0x8c,
+    },
+    Color {
+        r: 0xbd,
+        g: 0x93,
+        b: 0xf9,
+    },
+];
+
+// Color of nets and cells on a clock domain crossing
+const CLOCK_CROSSING_COLOR: Color = Color {
+    r: 0xff,
+    g: 0x40,
+    b: 0xff,
+};
+
+// Colors of the pips driving into and out of an explored wire
+const UPHILL_COLOR: Color = Color {
+    r: 0x40,
+    g: 0xc0,
+    b: 0xff,
+};
+const DOWNHILL_COLOR: Color = Color {
+    r: 0xff,
+    g: 0xa0,
+    b: 0x40,
+};
+
+// Color of a candidate route found by the shortest path search
+const CANDIDATE_ROUTE_COLOR: Color = Color {
+    r: 0x50,
+    g: 0xfa,
+    b: 0x7b,
+};
+
+// Wires the shortest path search may expand before giving up
+const ROUTE_SEARCH_LIMIT: usize = 500_000;
+
+// Zoom level, in pixels per tile, from which unused pips are drawn
+const UNUSED_PIPS_MIN_SCALE: f32 = 150.0;
+
+/// What the shortest path search between two wires minimizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum RouteCost {
+    /// Number of pips.
+    Hops,
+    /// Sum of the maximum pip and wire delays.
+    Delay,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ColorConfig {
     active: Color,
@@ -47,26 +142,260 @@ pub struct ColorConfig {
 
 pub type CellColorConfig = FxHashMap<String, Color>;
 
+#[derive(Serialize)]
+pub struct BinUtilization {
+    pub x: i32,
+    pub y: i32,
+    pub used: u32,
+    pub available: u32,
+}
+
+#[derive(Serialize)]
+pub struct UtilizationSummary {
+    pub used: u32,
+    pub available: u32,
+    /// Utilization of the BEL type according to the report, if one was loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<Utilization>,
+    pub bins: Vec<BinUtilization>,
+}
+
+#[derive(Serialize)]
+pub struct ClockDomain {
+    pub net: String,
+    pub color: Color,
+    pub cells: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fmax: Option<Fmax>,
+}
+
+#[derive(Serialize)]
+pub struct GlobalClockUsage {
+    pub net: String,
+    /// BELs of the clock buffers (e.g. DCCA) driving the net.
+    pub drivers: Vec<String>,
+    pub spines: Vec<String>,
+    pub taps: usize,
+}
+
+#[derive(Serialize)]
+pub struct ClockNetworkUsage {
+    pub spines: usize,
+    pub taps: usize,
+    pub nets: Vec<GlobalClockUsage>,
+}
+
+#[derive(Serialize)]
+pub struct PackagePinInfo {
+    pub pin: String,
+    pub bel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dqs_group: Option<i32>,
+    /// Cell placed on the pin's BEL, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub io_standard: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PipDelay {
+    pub pip: String,
+    pub delay: i32,
+}
+
+#[derive(Serialize)]
+pub struct SinkDelay {
+    pub wire: String,
+    pub delay: f32,
+}
+
+#[derive(Serialize)]
+pub struct NetDelay {
+    pub net: String,
+    pub sinks: Vec<SinkDelay>,
+}
+
+/// A pip of the routing graph and the wire on its far end.
+#[derive(Serialize)]
+pub struct PipLink {
+    pub pip: String,
+    pub wire: String,
+    /// Whether the pip is used by the loaded design.
+    pub active: bool,
+}
+
+#[derive(Serialize)]
+pub struct WirePips {
+    pub wire: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+    pub uphill: Vec<PipLink>,
+    pub downhill: Vec<PipLink>,
+}
+
+#[derive(Serialize)]
+pub struct CandidateStep {
+    pub wire: String,
+    /// Pip driving `wire`, absent for the first wire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pip: Option<String>,
+}
+
+/// A route through the routing graph found by the shortest path search.
+#[derive(Serialize)]
+pub struct CandidateRoute {
+    pub hops: u32,
+    /// Sum of the maximum pip and wire delays, in picoseconds.
+    pub delay: i32,
+    pub steps: Vec<CandidateStep>,
+    /// Wires expanded by the search.
+    pub visited: u32,
+    /// Hops of the design's route between the same wires, if one net is routed through both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routed_hops: Option<u32>,
+    /// Delay of that route, including fanout adders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routed_delay: Option<i32>,
+}
+
+/// A wire reached by the shortest path search.
+struct SearchNode<DecalID> {
+    wire: DecalXY<DecalID>,
+    /// Previous node and the pip from it.
+    via: Option<(usize, DecalXY<DecalID>)>,
+    cost: i64,
+}
+
+#[derive(Serialize)]
+pub struct RegionUsage {
+    pub name: String,
+    pub used: u32,
+    pub available: u32,
+    pub wires_used: u32,
+    pub wires_available: u32,
+    pub usage: f32,
+}
+
+/// A BEL pin, the wire attached to it and the net routed through that wire.
+#[derive(Serialize, Deserialize)]
+pub struct BelPinInfo {
+    pub bel: String,
+    pub pin: String,
+    pub direction: String,
+    pub wire: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct DecalInfo<DecalID> {
     pub id: String,
     pub is_active: bool,
     pub is_critical: bool,
+    /// Bitstream tiles at the decal's location, see `Architecture::get_tile_names`.
+    pub tiles: Vec<String>,
+    /// Intrinsic delay of a pip or wire per speed grade.
+    pub delays: Vec<ResourceDelay>,
+    /// Pins of a BEL, or the BEL pins a wire is attached to.
+    pub pins: Vec<BelPinInfo>,
     pub internal: DecalXY<DecalID>,
 }
 
+/// A ball or pin of the package footprint, at `(x, y)` in package units.
+struct PackageBall {
+    x: f64,
+    y: f64,
+    bel: String,
+}
+
+struct PackageView<'a> {
+    balls: Vec<PackageBall>,
+    elements: WebGlElements<'a>,
+}
+
+/// Inactive geometry of the unused pips around the view, see `show_unused_pips`.
+struct UnusedPips<'a> {
+    /// Covered tiles as `(x1, y1, x2, y2)`, inclusive.
+    tiles: (i32, i32, i32, i32),
+    elements: WebGlElements<'a>,
+}
+
+/// Delays of the routed design from the chipdb's pip, wire and cell timing for one speed grade.
+struct RoutedDelayModel<'r, DecalID> {
+    architecture: &'r dyn Architecture<DecalID>,
+    speed: String,
+    /// Delay from the driver to each sink wire of every net, in nanoseconds.
+    sinks: Vec<Vec<(String, f32)>>,
+    /// Wire of each BEL pin of every placed cell, from the chipdb.
+    pin_wires: Vec<FxHashMap<String, String>>,
+}
+
+impl<DecalID> DelayModel for RoutedDelayModel<'_, DecalID> {
+    fn net_delay(&self, net: NetIndex, sink: CellIndex, port: &str) -> PinDelay {
+        let delays = &self.sinks[net];
+        let routed = self.pin_wires[sink]
+            .get(port)
+            .and_then(|wire| delays.iter().find(|(w, _)| w == wire));
+        match routed {
+            Some(&(_, delay)) => PinDelay {
+                delay,
+                estimated: false,
+            },
+            None => PinDelay {
+                delay: delays
+                    .iter()
+                    .map(|(_, delay)| *delay)
+                    .max_by(f32::total_cmp)
+                    .unwrap_or(0.0),
+                estimated: true,
+            },
+        }
+    }
+
+    fn cell_delay(&self, cell: &NetlistCell, from_port: &str, to_port: &str) -> Option<f32> {
+        self.architecture
+            .get_cell_delay(&cell.r#type, from_port, to_port, &self.speed)
+            .map(|d| d as f32 / 1000.0)
+    }
+
+    fn setup_time(&self, cell: &NetlistCell, port: &str, clock_port: &str) -> Option<f32> {
+        self.architecture
+            .get_cell_setup(&cell.r#type, port, clock_port, &self.speed)
+            .map(|d| d as f32 / 1000.0)
+    }
+}
+
 pub struct Renderer<'a, DecalID> {
     architecture: Box<dyn Architecture<DecalID>>,
     program: RenderingProgram,
     canvas: OffscreenCanvas,
 
     pnr_info: Option<PnrInfo>,
+    routed_pips: FxHashMap<String, String>,
+    route_playback: Vec<Vec<DecalPointer>>,
+    path_cursor: Option<(usize, usize)>,
 
     decals: FxHashMap<ElementType, FxHashMap<String, DecalXY<DecalID>>>,
     graphic_elements: GraphicElements,
     graphic_elements_dirty: bool,
+    /// Unrouted pips made drawable by exploring the routing graph, kept apart from the routed
+    /// pips in `decals` and `graphic_elements`.
+    explored_pips: FxHashMap<String, DecalXY<DecalID>>,
+    explored_pip_graphics: GraphicElementCollection,
     webgl_elements: WebGlElements<'a>,
     webgl_elements_dirty: bool,
+    overlay: WebGlElements<'a>,
+    /// `selection.marked` in its mark colors, rebuilt when `None`.
+    marked_elements: Option<WebGlElements<'a>>,
+    show_unused_pips: bool,
+    unused_pips: Option<UnusedPips<'a>>,
+    /// Package footprint drawn instead of the die, see `show_package_view`.
+    package_view: Option<PackageView<'a>>,
 
     rtree: Option<RTree<RTreeData>>,
     rtree_data: FxHashMap<RTreeElementIndex, RTreeElementData>,
@@ -122,12 +451,22 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             canvas,
 
             pnr_info: None,
+            routed_pips: FxHashMap::default(),
+            route_playback: vec![],
+            path_cursor: None,
 
             decals: FxHashMap::default(),
             graphic_elements: FxHashMap::default(),
             graphic_elements_dirty: true,
+            explored_pips: FxHashMap::default(),
+            explored_pip_graphics: FxHashMap::default(),
             webgl_elements: vec![],
             webgl_elements_dirty: true,
+            overlay: vec![],
+            marked_elements: None,
+            show_unused_pips: false,
+            unused_pips: None,
+            package_view: None,
 
             rtree: None,
             rtree_data: FxHashMap::default(),
@@ -140,6 +479,7 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             selection: DecalSelection {
                 selected: None,
                 highlighted: None,
+                marked: vec![],
             },
         })
     }
@@ -155,6 +495,8 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             self.canvas.height()
         ));
         self.ensure_webgl_elements()?;
+        self.ensure_unused_pips()?;
+        self.ensure_marked_elements()?;
 
         let gl = self.program.get_gl();
         let canvas = &self.canvas;
@@ -182,17 +524,58 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
                 canvas.height() as f32,
             )
         };
-        for elem in &self.webgl_elements {
+        if let Some(view) = &self.package_view {
+            for elem in &view.elements {
+                draw(elem)?
+            }
+
+            // Outline the balls of the highlighted and selected IO BELs
+            let mut outlines = vec![];
+            for (selection, color) in [
+                (&self.selection.highlighted, self.colors.highlight),
+                (&self.selection.selected, self.colors.selected),
+            ] {
+                let Some((ElementType::Bel, bel)) = selection else {
+                    continue;
+                };
+                for ball in view.balls.iter().filter(|b| &b.bel == bel) {
+                    let mut el = GraphicElement::new(Type::Box, Style::Active);
+                    el.x1 = ball.x + 0.05;
+                    el.y1 = ball.y + 0.05;
+                    el.x2 = ball.x + 0.95;
+                    el.y2 = ball.y + 0.95;
+                    el.color = Some(color);
+                    outlines.push(el);
+                }
+            }
+            let items = outlines.iter().map(|g| (&ElementType::Group, "", g));
+            let (outline_elems, _, _) = self.to_webgl_elements(items, None)?;
+            for elem in outline_elems {
+                draw(&elem)?
+            }
+
+            return Ok(());
+        }
+
+        // Overlays (e.g. heatmaps) go underneath everything else, then the unused pips
+        let unused_pips = self.unused_pips.iter().flat_map(|p| &p.elements);
+        for elem in self
+            .overlay
+            .iter()
+            .chain(unused_pips)
+            .chain(&self.webgl_elements)
+        {
+            draw(elem)?
+        }
+
+        // Draw over marked decals (e.g. a logic cone) with their mark color
+        for elem in self.marked_elements.iter().flatten() {
             draw(elem)?
         }
 
         // Draw over selected/highlighted decal with highlight color
         let draw_decal = |decal_id: &str, etype: &ElementType, color: Color| -> Result<()> {
-            if let Some(ge_vec) = self
-                .graphic_elements
-                .get(etype)
-                .and_then(|m| m.get(decal_id))
-            {
+            if let Some(ge_vec) = self.decal_graphics(etype, decal_id) {
                 let items = ge_vec.iter().map(|g| (etype, decal_id, g));
 
                 let (selection_elems, _, _) = self.to_webgl_elements(items, Some(color))?;
@@ -216,7 +599,7 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         Ok(())
     }
 
-    pub fn show_json(&mut self, pnr_info: PnrInfo, auto_render: bool) -> Result<()> {
+    pub fn show_json(&mut self, mut pnr_info: PnrInfo, auto_render: bool) -> Result<()> {
         self.ensure_graphic_elements();
 
         let elems = pnr_info.get_elements();
@@ -283,6 +666,10 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         pip_map.clear();
         let pip_decal_map = self.decals.entry(ElementType::Pip).or_default();
         pip_decal_map.clear();
+        self.routed_pips.clear();
+        self.explored_pips.clear();
+        self.explored_pip_graphics.clear();
+        let mut pip_sources = FxHashMap::default();
         let mut pips_updated = 0usize;
         for pip in elems.pips {
             let Some(decal) =
@@ -291,8 +678,12 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             else {
                 continue;
             };
+            if let Some(src) = self.architecture.get_pip_src_node(&decal.decal) {
+                pip_sources.insert(pip.name.clone(), src);
+            }
 
             pip_decal_map.insert(decal.id.clone(), decal.clone());
+            self.routed_pips.insert(pip.name.clone(), decal.id.clone());
 
             let mut ge = self.architecture.get_decal_graphics(&decal.decal);
             for g in ge.iter_mut() {
@@ -308,8 +699,14 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         debug_log(format!("show_json:pips updated={}", pips_updated));
 
         self.webgl_elements_dirty = true;
+        self.overlay.clear();
+        self.unused_pips = None;
 
+        pnr_info.set_pip_sources(pip_sources);
         self.pnr_info = Some(pnr_info);
+        self.mark(vec![]);
+        self.route_playback.clear();
+        self.path_cursor = None;
 
         if auto_render {
             self.render()?;
@@ -413,6 +810,29 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         self.graphic_elements_dirty = false;
     }
 
+    /// Builds the WebGL elements of the marked decals (e.g. a logic cone) after they change.
+    fn ensure_marked_elements(&mut self) -> Result<()> {
+        if self.marked_elements.is_some() {
+            return Ok(());
+        }
+
+        let mut marked_elements = vec![];
+        for (color, decals) in &self.selection.marked {
+            let items = decals.iter().flat_map(|(etype, decal_id)| {
+                self.decal_graphics(etype, decal_id)
+                    .into_iter()
+                    .flatten()
+                    .map(move |g| (etype, decal_id.as_str(), g))
+            });
+
+            let (elems, _, _) = self.to_webgl_elements(items, Some(*color))?;
+            marked_elements.extend(elems);
+        }
+        self.marked_elements = Some(marked_elements);
+
+        Ok(())
+    }
+
     pub fn ensure_webgl_elements(&mut self) -> Result<()> {
         // Make sure graphic elements are updated first
         self.ensure_graphic_elements();
@@ -433,11 +853,16 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         ));
 
         // Preserve decal IDs while iterating the nested maps.
-        let iter = self.graphic_elements.iter().flat_map(|(etype, id_map)| {
-            id_map.iter().flat_map(move |(decal_id, ge_vec)| {
-                ge_vec.iter().map(move |ge| (etype, decal_id.as_str(), ge))
-            })
-        });
+        let explored = (&ElementType::Pip, &self.explored_pip_graphics);
+        let iter = self
+            .graphic_elements
+            .iter()
+            .chain([explored])
+            .flat_map(|(etype, id_map)| {
+                id_map.iter().flat_map(move |(decal_id, ge_vec)| {
+                    ge_vec.iter().map(move |ge| (etype, decal_id.as_str(), ge))
+                })
+            });
 
         let (webgl, rtree_elems, rtree_data) = self.to_webgl_elements(iter, None)?;
         self.webgl_elements = webgl;
@@ -445,6 +870,7 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         self.rtree_data = rtree_data;
 
         self.webgl_elements_dirty = false;
+        self.marked_elements = None;
         debug_log(format!(
             "ensure_webgl_elements: done webgl_elems={}",
             self.webgl_elements.len()
@@ -681,10 +1107,18 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             map
         };
 
-        let decal_map = self.decals.entry(element_type).or_default();
+        let netlist = self.pnr_info.as_ref().map(|p| p.get_netlist());
 
         FxHashMap::from_iter(decal_ids.iter().filter_map(|decal_id| {
-            let decal = decal_map.get(decal_id.as_str())?;
+            // Device pips the design does not use are looked up without drawing them
+            let decal = self
+                .lookup_decal(&element_type, decal_id)
+                .cloned()
+                .or_else(|| {
+                    (element_type == ElementType::Pip)
+                        .then(|| self.architecture.find_pip_decal_by_name(decal_id))
+                        .flatten()
+                })?;
 
             let is_critical = crit_decals
                 .get(&element_type)
@@ -692,9 +1126,7 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
 
             let is_active = is_critical // all critical decals are active
                 || self
-                    .graphic_elements
-                    .get(&element_type)
-                    .and_then(|m| m.get(&decal.id))
+                    .decal_graphics(&element_type, &decal.id)
                     .is_some_and(|ge_vec| {
                         ge_vec.iter().any(|g| g.style == Style::Active)
                     });
@@ -705,7 +1137,17 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
                     id: decal_id.clone(),
                     is_active,
                     is_critical,
-                    internal: decal.clone(),
+                    tiles: self.architecture.get_decal_tiles(&decal.decal),
+                    delays: self.architecture.get_decal_delays(&decal.decal),
+                    pins: match element_type {
+                        ElementType::Bel => self.architecture.get_bel_pins(&decal.decal),
+                        ElementType::Wire => self.architecture.get_wire_bel_pins(&decal.decal),
+                        _ => vec![],
+                    }
+                    .into_iter()
+                    .map(|pin| bel_pin_info(netlist, pin))
+                    .collect(),
+                    internal: decal,
                 },
             ))
         }))
@@ -722,6 +1164,10 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
             "select_decal: type={:?} id={} zoom={} full_select={}",
             element_type, decal_id, do_zoom, is_full_select
         ));
+        if element_type == ElementType::Pip {
+            self.ensure_pip_decal(decal_id);
+        }
+
         if is_full_select {
             self.selection.selected = Some((element_type, decal_id.to_string()));
         } else {
@@ -729,50 +1175,7 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         }
 
         if do_zoom {
-            // Calculate the bounding box of the selected decal from its graphic elements
-            if let Some(ge_vec) = self
-                .graphic_elements
-                .get(&element_type)
-                .and_then(|m| m.get(decal_id))
-            {
-                if !ge_vec.is_empty() {
-                    // Find the bounding box of all graphic elements
-                    let mut min_x = f32::INFINITY;
-                    let mut max_x = f32::NEG_INFINITY;
-                    let mut min_y = f32::INFINITY;
-                    let mut max_y = f32::NEG_INFINITY;
-
-                    for ge in ge_vec {
-                        min_x = min_x.min(ge.x1 as f32).min(ge.x2 as f32);
-                        max_x = max_x.max(ge.x1 as f32).max(ge.x2 as f32);
-                        min_y = min_y.min(ge.y1 as f32).min(ge.y2 as f32);
-                        max_y = max_y.max(ge.y1 as f32).max(ge.y2 as f32);
-                    }
-
-                    // Calculate center of the bounding box
-                    let center_x = (min_x + max_x) / 2.0;
-                    let center_y = (min_y + max_y) / 2.0;
-
-                    // Calculate dimensions
-                    let width = max_x - min_x;
-                    let height = max_y - min_y;
-
-                    // Set scale to fit the decal in view with some padding (80% of canvas)
-                    let canvas_width = self.canvas.width() as f32;
-                    let canvas_height = self.canvas.height() as f32;
-
-                    let scale_x = (canvas_width * 0.8) / width;
-                    let scale_y = (canvas_height * 0.8) / height;
-                    self.scale = scale_x.min(scale_y).clamp(10.0, 4000.0);
-
-                    // Center the view on the decal
-                    // The shader does: (position.x - offset.x) / canvas_width * scale
-                    // For Y: (-position.y - offset.y) / canvas_height * scale
-                    // To center at canvas_center, we need the above to equal 0.5
-                    self.offset.0 = center_x - (canvas_width / 2.0) / self.scale;
-                    self.offset.1 = -center_y - (canvas_height / 2.0) / self.scale;
-                }
-            }
+            self.zoom_to_decals(&[(element_type, decal_id.to_string())]);
         }
 
         self.render()?;
@@ -780,141 +1183,1770 @@ impl<'a, DecalID: Clone> Renderer<'a, DecalID> {
         Ok(())
     }
 
-    pub fn cancel_selection(&mut self, is_full_select: bool) -> Result<()> {
-        if is_full_select {
-            self.selection.selected = None;
+    fn zoom_to_decals(&mut self, decals: &[DecalPointer]) {
+        // Calculate the bounding box of the decals from their graphic elements
+        let mut min_x = f32::INFINITY;
+        let mut max_x = f32::NEG_INFINITY;
+        let mut min_y = f32::INFINITY;
+        let mut max_y = f32::NEG_INFINITY;
+
+        if let Some(view) = &self.package_view {
+            // In the package view, BELs are shown by their balls
+            let balls = view.balls.iter().filter(|b| {
+                decals
+                    .iter()
+                    .any(|(etype, id)| *etype == ElementType::Bel && *id == b.bel)
+            });
+            for ball in balls {
+                min_x = min_x.min(ball.x as f32);
+                max_x = max_x.max(ball.x as f32 + 1.0);
+                min_y = min_y.min(ball.y as f32);
+                max_y = max_y.max(ball.y as f32 + 1.0);
+            }
         } else {
-            self.selection.highlighted = None;
+            let ges = decals.iter().flat_map(|(etype, decal_id)| {
+                self.decal_graphics(etype, decal_id).into_iter().flatten()
+            });
+            for ge in ges {
+                min_x = min_x.min(ge.x1 as f32).min(ge.x2 as f32);
+                max_x = max_x.max(ge.x1 as f32).max(ge.x2 as f32);
+                min_y = min_y.min(ge.y1 as f32).min(ge.y2 as f32);
+                max_y = max_y.max(ge.y1 as f32).max(ge.y2 as f32);
+            }
         }
 
-        self.render()?;
+        self.zoom_to_bounds(min_x, max_x, min_y, max_y);
+    }
 
-        Ok(())
+    fn zoom_to_bounds(&mut self, min_x: f32, max_x: f32, min_y: f32, max_y: f32) {
+        if min_x > max_x || min_y > max_y {
+            return;
+        }
+
+        // Calculate center of the bounding box
+        let center_x = (min_x + max_x) / 2.0;
+        let center_y = (min_y + max_y) / 2.0;
+
+        // Calculate dimensions
+        let width = max_x - min_x;
+        let height = max_y - min_y;
+
+        // Set scale to fit the decals in view with some padding (80% of canvas)
+        let canvas_width = self.canvas.width() as f32;
+        let canvas_height = self.canvas.height() as f32;
+
+        let scale_x = (canvas_width * 0.8) / width;
+        let scale_y = (canvas_height * 0.8) / height;
+        self.scale = scale_x.min(scale_y).clamp(10.0, 4000.0);
+
+        // Center the view on the decals
+        // The shader does: (position.x - offset.x) / canvas_width * scale
+        // For Y: (-position.y - offset.y) / canvas_height * scale
+        // To center at canvas_center, we need the above to equal 0.5
+        self.offset.0 = center_x - (canvas_width / 2.0) / self.scale;
+        self.offset.1 = -center_y - (canvas_height / 2.0) / self.scale;
     }
 
-    fn canvas_to_world(&self, x: f32, y: f32) -> (f32, f32) {
-        let wx = (x / self.scale) + self.offset.0;
-        // Don't flip Y - the vertex shader handles Y-axis transformation
-        let wy = -(y / self.scale) - self.offset.1;
-        (wx, wy)
+    fn get_net_decals(&self, pnr_info: &PnrInfo, net: &String) -> Vec<DecalPointer> {
+        let wires = pnr_info
+            .get_net_wires(net)
+            .into_iter()
+            .map(|w| (ElementType::Wire, w));
+        let pips = pnr_info
+            .get_net_routing(net)
+            .into_iter()
+            .filter_map(|r| self.routed_pips.get(&r.pip.name))
+            .map(|p| (ElementType::Pip, p.clone()));
+
+        wires.chain(pips).collect()
     }
 
-    pub fn select_decal_at_world(
+    pub fn highlight_cone(
         &mut self,
-        x: f32,
-        y: f32,
-        is_full_select: bool,
-    ) -> Result<Option<DecalPointer>> {
-        let Some(rtree) = &self.rtree else {
-            return Ok(None);
+        element_type: ElementType,
+        decal_id: &str,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Result<Cone> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
         };
-        let rtree_data = &self.rtree_data;
+        let netlist = pnr_info.get_netlist();
 
-        let selection = {
-            let ge_map = &self.graphic_elements;
-            let line_dist = |p1: [f32; 2], p2: [f32; 2], point: [f32; 2]| {
-                let line_vec = [p2[0] - p1[0], p2[1] - p1[1]];
-                let line_len_2 = line_vec[0] * line_vec[0] + line_vec[1] * line_vec[1];
-                let t = if line_len_2 > 0.0 {
-                    ((point[0] - p1[0]) * line_vec[0] + (point[1] - p1[1]) * line_vec[1])
-                        / line_len_2
-                } else {
-                    0.0
-                };
-                let t_clamped = t.clamp(0.0, 1.0);
-                let closest = [
-                    p1[0] + t_clamped * line_vec[0],
-                    p1[1] + t_clamped * line_vec[1],
-                ];
-                ((point[0] - closest[0]) * (point[0] - closest[0])
-                    + (point[1] - closest[1]) * (point[1] - closest[1]))
-                    .sqrt()
-            };
-            let score = |etype: ElementType, decal_id: &str| -> (u8, f32) {
-                let mut best_line: Option<f32> = None;
-                let mut best_rect: Option<f32> = None;
+        let (start_cell, start_net) = match element_type {
+            ElementType::Bel => (netlist.find_cell_by_bel(decal_id), None),
+            ElementType::Wire => (None, netlist.find_net_by_wire(decal_id)),
+            _ => bail!("Cones can only start at a BEL or a wire"),
+        };
+        if start_cell.is_none() && start_net.is_none() {
+            bail!("{decal_id} is not used by the design");
+        }
 
-                if let Some(ge_vec) = ge_map.get(&etype).and_then(|m| m.get(decal_id)) {
-                    for ge in ge_vec {
-                        match ge.r#type {
-                            Type::Line | Type::Arrow | Type::LocalLine | Type::LocalArrow => {
-                                let d = line_dist(
-                                    [ge.x1 as f32, ge.y1 as f32],
-                                    [ge.x2 as f32, ge.y2 as f32],
-                                    [x, y],
-                                );
-                                best_line = Some(best_line.map_or(d, |best| best.min(d)));
-                            }
-                            Type::Box | Type::FilledBox => {
-                                let (minx, maxx) = (
-                                    (ge.x1 as f32).min(ge.x2 as f32),
-                                    (ge.x1 as f32).max(ge.x2 as f32),
-                                );
-                                let (miny, maxy) = (
-                                    (ge.y1 as f32).min(ge.y2 as f32),
-                                    (ge.y1 as f32).max(ge.y2 as f32),
-                                );
-                                let area = (maxx - minx).abs() * (maxy - miny).abs();
-                                best_rect = Some(best_rect.map_or(area, |best| best.min(area)));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
+        self.route_playback.clear();
+        let cone = netlist.get_cone(start_cell, start_net, direction, levels, stop_at_ff);
+
+        let bels = cone
+            .cells
+            .iter()
+            .filter_map(|c| netlist.find_cell(c))
+            .map(|c| (ElementType::Bel, netlist.cells[c].bel.clone()));
+        let nets = cone
+            .nets
+            .iter()
+            .flat_map(|n| self.get_net_decals(pnr_info, n));
+        let marked: Vec<DecalPointer> = bels.chain(nets).collect();
+
+        self.zoom_to_decals(&marked);
+        self.set_marked(marked);
+        self.render()?;
 
-                if let Some(d) = best_line {
-                    if d <= PICK_EPSILON {
-                        return (0, d);
-                    }
-                    if let Some(area) = best_rect {
-                        return (1, area);
-                    }
-                    return (2, d);
-                }
+        Ok(cone)
+    }
 
-                if let Some(area) = best_rect {
-                    return (1, area);
-                }
+    pub fn get_wire_net(&self, wire: &str) -> Option<String> {
+        let netlist = self.pnr_info.as_ref()?.get_netlist();
+        netlist
+            .find_net_by_wire(wire)
+            .map(|n| netlist.nets[n].name.clone())
+    }
 
-                (3, 0.0)
-            };
+    /// Pip decals driving into (uphill) and out of (downhill) a wire, each paired with the wire
+    /// decal on its far end.
+    fn wire_pips(&mut self, wire: &str) -> Result<WireHops<DecalID>> {
+        let decal = self.find_decal(ElementType::Wire, wire)?;
 
-            rtree
-                .locate_all_at_point(&[x, y])
-                .map(|entry| {
-                    rtree_data
-                        .get(&entry.data)
-                        .expect("RTree data should be in sync")
-                        .clone()
-                })
-                .min_by(|a, b| {
-                    let sa = score(a.0, &a.1);
-                    let sb = score(b.0, &b.1);
+        let arch = &self.architecture;
+        let uphill = arch
+            .get_pips_uphill(&decal.decal)
+            .into_iter()
+            .filter_map(|pip| {
+                let (src, _) = arch.get_pip_wires(&pip.decal)?;
+                Some((pip, src))
+            })
+            .collect();
+        let downhill = arch
+            .get_pips_downhill(&decal.decal)
+            .into_iter()
+            .filter_map(|pip| {
+                let (_, dst) = arch.get_pip_wires(&pip.decal)?;
+                Some((pip, dst))
+            })
+            .collect();
 
-                    sa.0.cmp(&sb.0).then_with(|| sa.1.total_cmp(&sb.1))
+        Ok((uphill, downhill))
+    }
+
+    /// Lists the pips driving into and out of a wire with the wire on the far end of each,
+    /// the next steps when walking the routing graph.
+    pub fn get_wire_pips(&mut self, wire: &str) -> Result<WirePips> {
+        let (uphill, downhill) = self.wire_pips(wire)?;
+
+        let routed: FxHashSet<&String> = self.routed_pips.values().collect();
+        let links = |pips: Vec<PipHop<DecalID>>| {
+            pips.into_iter()
+                .map(|(pip, wire)| PipLink {
+                    active: routed.contains(&pip.id),
+                    pip: pip.id,
+                    wire: wire.id,
                 })
+                .collect()
         };
 
-        if let Some((etype, decal_id)) = selection {
-            self.select_decal(etype, &decal_id, false, is_full_select)?;
-        } else {
-            self.cancel_selection(is_full_select)?;
-        }
+        Ok(WirePips {
+            wire: wire.to_string(),
+            net: self.get_wire_net(wire),
+            uphill: links(uphill),
+            downhill: links(downhill),
+        })
+    }
 
-        if is_full_select {
-            Ok(self.selection.selected.clone())
-        } else {
-            Ok(self.selection.highlighted.clone())
+    /// Selects a wire and highlights the pips and wires uphill and downhill of it. Pips not used
+    /// by the design are added as inactive geometry so they can be drawn and picked; calling this
+    /// again with a wire from the result walks the routing graph.
+    pub fn show_wire_pips(&mut self, wire: &str) -> Result<WirePips> {
+        let (uphill, downhill) = self.wire_pips(wire)?;
+
+        let mut marked = vec![];
+        for (color, pips) in [(UPHILL_COLOR, &uphill), (DOWNHILL_COLOR, &downhill)] {
+            let mut decals = vec![];
+            for (pip, far_wire) in pips {
+                self.add_pip_decal(pip);
+                decals.push((ElementType::Pip, pip.id.clone()));
+                decals.push((ElementType::Wire, far_wire.id.clone()));
+            }
+            marked.push((color, decals));
         }
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.selection.selected = Some((ElementType::Wire, wire.to_string()));
+        self.mark(marked);
+        self.render()?;
+
+        self.get_wire_pips(wire)
     }
 
-    pub fn select_decal_at_canvas(
-        &mut self,
-        x: f32,
-        y: f32,
-        is_full_select: bool,
-    ) -> Result<Option<DecalPointer>> {
-        let (wx, wy) = self.canvas_to_world(x, y);
-        self.select_decal_at_world(wx, wy, is_full_select)
+    fn find_decal(&mut self, element_type: ElementType, id: &str) -> Result<DecalXY<DecalID>> {
+        self.ensure_graphic_elements();
+
+        let Some(decal) = self.lookup_decal(&element_type, id) else {
+            bail!("{element_type:?} {id} does not exist");
+        };
+        Ok(decal.clone())
+    }
+
+    /// A decal by id, including the explored pips.
+    fn lookup_decal(&self, element_type: &ElementType, id: &str) -> Option<&DecalXY<DecalID>> {
+        self.decals
+            .get(element_type)
+            .and_then(|m| m.get(id))
+            .or_else(|| match element_type {
+                ElementType::Pip => self.explored_pips.get(id),
+                _ => None,
+            })
+    }
+
+    /// Graphics of a decal by id, including the explored pips.
+    fn decal_graphics(&self, element_type: &ElementType, id: &str) -> Option<&Vec<GraphicElement>> {
+        self.graphic_elements
+            .get(element_type)
+            .and_then(|m| m.get(id))
+            .or_else(|| match element_type {
+                ElementType::Pip => self.explored_pip_graphics.get(id),
+                _ => None,
+            })
+    }
+
+    /// Pins of a BEL with the wires attached to them and the nets routed through those wires.
+    pub fn get_bel_pins(&mut self, bel: &str) -> Result<Vec<BelPinInfo>> {
+        let decal = self.find_decal(ElementType::Bel, bel)?;
+        let netlist = self.pnr_info.as_ref().map(|p| p.get_netlist());
+
+        Ok(self
+            .architecture
+            .get_bel_pins(&decal.decal)
+            .into_iter()
+            .map(|pin| bel_pin_info(netlist, pin))
+            .collect())
+    }
+
+    /// BEL pins a wire is attached to, e.g. the LUT input a route lands on.
+    pub fn get_wire_bel_pins(&mut self, wire: &str) -> Result<Vec<BelPinInfo>> {
+        let decal = self.find_decal(ElementType::Wire, wire)?;
+        let netlist = self.pnr_info.as_ref().map(|p| p.get_netlist());
+
+        Ok(self
+            .architecture
+            .get_wire_bel_pins(&decal.decal)
+            .into_iter()
+            .map(|pin| bel_pin_info(netlist, pin))
+            .collect())
+    }
+
+    /// Selects a BEL and highlights the wires attached to its pins: those carrying a net in the
+    /// highlight color, the unused ones in the frame color.
+    pub fn show_bel_pins(&mut self, bel: &str) -> Result<Vec<BelPinInfo>> {
+        let pins = self.get_bel_pins(bel)?;
+
+        let (used, unused): (Vec<_>, Vec<_>) = pins.iter().partition(|p| p.net.is_some());
+        let wires = |pins: Vec<&BelPinInfo>| {
+            pins.into_iter()
+                .map(|p| (ElementType::Wire, p.wire.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.selection.selected = Some((ElementType::Bel, bel.to_string()));
+        self.mark(vec![
+            (self.colors.frame, wires(unused)),
+            (self.colors.highlight, wires(used)),
+        ]);
+        self.render()?;
+
+        Ok(pins)
+    }
+
+    /// Hops and delay of the design's route from `from` to `to`, if a net is routed through both
+    /// and `from` lies on its path to `to`.
+    fn routed_between(&self, from: &str, to: &str, speed: &str) -> Option<(u32, i32)> {
+        let pnr_info = self.pnr_info.as_ref()?;
+        let net = self.get_wire_net(from)?;
+        if self.get_wire_net(to).as_ref() != Some(&net) {
+            return None;
+        }
+
+        let tree = pnr_info.get_routing_tree(&net)?;
+        let path = tree.path_to(tree.find_wire(to)?);
+        let start = path.iter().position(|&n| tree.nodes[n].wire == from)?;
+        let delay = path[start + 1..]
+            .iter()
+            .map(|&n| self.route_node_delay(&tree, &tree.nodes[n], speed))
+            .sum();
+
+        Some(((path.len() - 1 - start) as u32, delay))
+    }
+
+    /// Finds the shortest route from one wire to another through the routing graph, by number
+    /// of pips or by delay for a speed grade (the first one by default), and highlights it as a
+    /// candidate route. The route of the design between the same wires, if any, is reported
+    /// for comparison.
+    pub fn find_shortest_route(
+        &mut self,
+        from_wire: &str,
+        to_wire: &str,
+        cost: RouteCost,
+        speed: Option<&str>,
+    ) -> Result<CandidateRoute> {
+        let speed = self.resolve_speed(speed)?;
+        let from = self.find_decal(ElementType::Wire, from_wire)?;
+        self.find_decal(ElementType::Wire, to_wire)?;
+
+        let (path, visited) =
+            search_route(self.architecture.as_ref(), from, to_wire, cost, &speed)?;
+
+        let mut marked = vec![];
+        let mut steps = vec![];
+        let mut delay = 0;
+        for (pip, wire) in &path {
+            // The delay of the first wire is not part of the route
+            if let Some(pip) = pip {
+                self.add_pip_decal(pip);
+                marked.push((ElementType::Pip, pip.id.clone()));
+                delay += max_delay(self.architecture.as_ref(), &pip.decal, &speed)
+                    + max_delay(self.architecture.as_ref(), &wire.decal, &speed);
+            }
+            marked.push((ElementType::Wire, wire.id.clone()));
+            steps.push(CandidateStep {
+                wire: wire.id.clone(),
+                pip: pip.as_ref().map(|p| p.id.clone()),
+            });
+        }
+        let routed = self.routed_between(from_wire, to_wire, &speed);
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.zoom_to_decals(&marked);
+        self.mark(vec![(CANDIDATE_ROUTE_COLOR, marked)]);
+        self.render()?;
+
+        Ok(CandidateRoute {
+            hops: (path.len() - 1) as u32,
+            delay,
+            steps,
+            visited,
+            routed_hops: routed.map(|(hops, _)| hops),
+            routed_delay: routed.map(|(_, delay)| delay),
+        })
+    }
+
+    /// Same as `find_shortest_route`, ending at the wire attached to a pin of a BEL.
+    pub fn find_shortest_route_to_pin(
+        &mut self,
+        from_wire: &str,
+        bel: &str,
+        pin: &str,
+        cost: RouteCost,
+        speed: Option<&str>,
+    ) -> Result<CandidateRoute> {
+        let Some(bel_pin) = self.get_bel_pins(bel)?.into_iter().find(|p| p.pin == pin) else {
+            bail!("BEL {bel} has no pin {pin}");
+        };
+        self.find_shortest_route(from_wire, &bel_pin.wire, cost, speed)
+    }
+
+    /// Looks up a device pip by name and makes it drawable if the design does not use it, so
+    /// that any pip can be selected and inspected.
+    fn ensure_pip_decal(&mut self, id: &str) {
+        if self.lookup_decal(&ElementType::Pip, id).is_some() {
+            return;
+        }
+
+        if let Some(pip) = self.architecture.find_pip_decal_by_name(id) {
+            self.add_pip_decal(&pip);
+        }
+    }
+
+    /// Names of the device pips from `offset` on, in chipdb order, including the ones the
+    /// design does not use.
+    pub fn get_device_pip_ids(&self, offset: usize, count: usize) -> Vec<String> {
+        self.architecture
+            .get_pip_decals()
+            .skip(offset)
+            .take(count)
+            .map(|p| p.id)
+            .collect()
+    }
+
+    /// Names of up to `limit` device pips containing `query`, e.g. a wire name.
+    pub fn search_pips(&self, query: &str, limit: usize) -> Vec<String> {
+        self.architecture
+            .get_pip_decals()
+            .filter(|p| p.id.contains(query))
+            .take(limit)
+            .map(|p| p.id)
+            .collect()
+    }
+
+    /// Draws the pips not used by the design in the inactive color once zoomed in far enough.
+    pub fn show_unused_pips(&mut self, enabled: bool) -> Result<()> {
+        self.show_unused_pips = enabled;
+        self.unused_pips = None;
+        self.render()
+    }
+
+    // Rebuilds the unused pips when the view leaves the tiles they cover
+    fn ensure_unused_pips(&mut self) -> Result<()> {
+        if !self.show_unused_pips
+            || self.package_view.is_some()
+            || self.scale < UNUSED_PIPS_MIN_SCALE
+        {
+            self.unused_pips = None;
+            return Ok(());
+        }
+
+        let (wx1, wy1) = self.canvas_to_world(0.0, 0.0);
+        let (wx2, wy2) =
+            self.canvas_to_world(self.canvas.width() as f32, self.canvas.height() as f32);
+        let (x1, x2) = (wx1.min(wx2).floor() as i32, wx1.max(wx2).ceil() as i32);
+        let (y1, y2) = (wy1.min(wy2).floor() as i32, wy1.max(wy2).ceil() as i32);
+        if let Some(UnusedPips { tiles, .. }) = &self.unused_pips {
+            if tiles.0 <= x1 && tiles.1 <= y1 && x2 <= tiles.2 && y2 <= tiles.3 {
+                return Ok(());
+            }
+        }
+
+        // Cover half a screen around the view so that panning rarely rebuilds
+        let (dx, dy) = ((x2 - x1) / 2 + 1, (y2 - y1) / 2 + 1);
+        let tiles = (x1 - dx, y1 - dy, x2 + dx, y2 + dy);
+        let mut ge = vec![];
+        for y in tiles.1..=tiles.3 {
+            for x in tiles.0..=tiles.2 {
+                for pip in self.architecture.get_tile_pip_decals(x, y) {
+                    if self.lookup_decal(&ElementType::Pip, &pip.id).is_some() {
+                        continue;
+                    }
+                    ge.extend(
+                        self.architecture
+                            .get_decal_graphics(&pip.decal)
+                            .into_iter()
+                            .map(|mut g| {
+                                g.style = Style::Inactive;
+                                g
+                            }),
+                    );
+                }
+            }
+        }
+        debug_log(format!(
+            "ensure_unused_pips: tiles={tiles:?} elems={}",
+            ge.len()
+        ));
+
+        let items = ge.iter().map(|g| (&ElementType::Pip, "", g));
+        let (elements, _, _) = self.to_webgl_elements(items, None)?;
+        self.unused_pips = Some(UnusedPips { tiles, elements });
+        Ok(())
+    }
+
+    /// Makes an unrouted pip drawable as inactive geometry, routed pips are left as they are.
+    fn add_pip_decal(&mut self, pip: &DecalXY<DecalID>) {
+        if self.lookup_decal(&ElementType::Pip, &pip.id).is_some() {
+            return;
+        }
+        self.explored_pips.insert(pip.id.clone(), pip.clone());
+
+        let mut ge = self.architecture.get_decal_graphics(&pip.decal);
+        for g in ge.iter_mut() {
+            g.style = Style::Inactive;
+        }
+        self.explored_pip_graphics.insert(pip.id.clone(), ge);
+        self.webgl_elements_dirty = true;
+        // It would be drawn twice otherwise
+        self.unused_pips = None;
+    }
+
+    pub fn get_route_info(&self, net: &String) -> Result<RouteInfo> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let Some(tree) = pnr_info.get_routing_tree(net) else {
+            bail!("Net {net} does not exist");
+        };
+
+        Ok(tree.to_route_info())
+    }
+
+    /// Highlights the routing of a net, or only the path from its driver to `sink_wire`.
+    pub fn highlight_route(&mut self, net: &String, sink_wire: Option<&str>) -> Result<()> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let Some(tree) = pnr_info.get_routing_tree(net) else {
+            bail!("Net {net} does not exist");
+        };
+
+        let nodes: Vec<_> = match sink_wire {
+            Some(wire) => {
+                let Some(sink) = tree.find_wire(wire) else {
+                    bail!("Wire {wire} is not part of net {net}");
+                };
+                tree.path_to(sink)
+            }
+            None => (0..tree.nodes.len()).collect(),
+        };
+
+        let marked: Vec<DecalPointer> = nodes
+            .into_iter()
+            .flat_map(|i| self.get_route_node_decals(&tree.nodes[i]))
+            .collect();
+
+        self.route_playback.clear();
+        self.zoom_to_decals(&marked);
+        self.set_marked(marked);
+        self.render()
+    }
+
+    fn set_marked(&mut self, decals: Vec<DecalPointer>) {
+        self.mark(vec![(self.colors.highlight, decals)]);
+    }
+
+    /// Replaces the marked decals and their colors, see `ensure_marked_elements`.
+    fn mark(&mut self, marked: Vec<(Color, Vec<DecalPointer>)>) {
+        self.selection.marked = marked;
+        self.marked_elements = None;
+    }
+
+    fn get_route_node_decals(&self, node: &RouteNode) -> Vec<DecalPointer> {
+        let pip = node
+            .pip
+            .as_ref()
+            .and_then(|p| self.routed_pips.get(&p.name))
+            .map(|p| (ElementType::Pip, p.clone()));
+
+        pip.into_iter()
+            .chain([(ElementType::Wire, node.wire.clone())])
+            .collect()
+    }
+
+    /// Prepares playback of a net's routing, revealing it one hop depth at a time.
+    /// Returns the number of steps, step 0 shows only the driver.
+    pub fn start_route_playback(&mut self, net: &String) -> Result<u32> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let Some(tree) = pnr_info.get_routing_tree(net) else {
+            bail!("Net {net} does not exist");
+        };
+        if tree.nodes.is_empty() {
+            bail!("Net {net} has no routing");
+        }
+
+        let steps = tree.nodes.iter().map(|n| n.depth + 1).max().unwrap_or(0);
+        let mut playback = vec![vec![]; steps as usize];
+        for node in &tree.nodes {
+            playback[node.depth as usize].extend(self.get_route_node_decals(node));
+        }
+
+        let all: Vec<DecalPointer> = playback.iter().flatten().cloned().collect();
+        self.zoom_to_decals(&all);
+        self.route_playback = playback;
+        self.set_route_playback_step(0)?;
+
+        Ok(steps)
+    }
+
+    /// Shows the routing of the net passed to `start_route_playback` up to hop depth `step`.
+    pub fn set_route_playback_step(&mut self, step: u32) -> Result<()> {
+        if self.route_playback.is_empty() {
+            bail!("No route playback started");
+        }
+
+        let marked = self
+            .route_playback
+            .iter()
+            .take(step as usize + 1)
+            .flatten()
+            .cloned()
+            .collect();
+        self.set_marked(marked);
+        self.render()
+    }
+
+    /// BELs with a pin on a wire.
+    fn wire_bels(&self, wire: &str) -> Vec<String> {
+        self.decals
+            .get(&ElementType::Wire)
+            .and_then(|m| m.get(wire))
+            .map(|decal| {
+                self.architecture
+                    .get_wire_bel_pins(&decal.decal)
+                    .into_iter()
+                    .map(|p| p.bel.id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_detours(&self, count: usize) -> Result<Vec<NetDetour>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+
+        let mut detours = pnr_info.get_detours(|wire| self.wire_bels(wire));
+        detours.truncate(count);
+        Ok(detours)
+    }
+
+    /// Highlights the path to the worst sink of the `count` nets with the largest detours.
+    pub fn highlight_detours(&mut self, count: usize) -> Result<Vec<NetDetour>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+
+        let mut detours = pnr_info.get_detours(|wire| self.wire_bels(wire));
+        detours.truncate(count);
+
+        let mut marked = vec![];
+        for detour in &detours {
+            let Some(tree) = pnr_info.get_routing_tree(&detour.net) else {
+                continue;
+            };
+            let Some(sink) = tree.find_wire(&detour.sink) else {
+                continue;
+            };
+            marked.extend(
+                tree.path_to(sink)
+                    .into_iter()
+                    .flat_map(|i| self.get_route_node_decals(&tree.nodes[i])),
+            );
+        }
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.zoom_to_decals(&marked);
+        self.set_marked(marked);
+        self.render()?;
+
+        Ok(detours)
+    }
+
+    /// Moves the critical path cursor one segment forward or back and highlights that segment.
+    /// The first step starts at the first segment of the first critical path. Stepping past
+    /// either end of a path continues on the neighbouring path; the cursor stays put at the ends
+    /// of the first and last paths.
+    pub fn path_step(&mut self, step: PathStep) -> Result<PathSegmentInfo> {
+        let segment_at = |path, segment| {
+            self.pnr_info
+                .as_ref()
+                .and_then(|p| p.get_path_segment(path, segment))
+        };
+        let (path, segment) = match (self.path_cursor, step) {
+            (None, _) => (0, 0),
+            (Some((path, segment)), PathStep::Next) => {
+                if segment_at(path, segment + 1).is_some() {
+                    (path, segment + 1)
+                } else if segment_at(path + 1, 0).is_some() {
+                    (path + 1, 0)
+                } else {
+                    (path, segment)
+                }
+            }
+            (Some((path, 0)), PathStep::Prev) => match path.checked_sub(1) {
+                Some(prev) => segment_at(prev, 0)
+                    .map_or((path, 0), |info| (prev, info.segments.saturating_sub(1))),
+                None => (path, 0),
+            },
+            (Some((path, segment)), PathStep::Prev) => (path, segment - 1),
+        };
+
+        self.select_path_segment(path, segment)
+    }
+
+    /// Zooms to and highlights the BELs and routed net of a critical path segment.
+    pub fn select_path_segment(&mut self, path: usize, segment: usize) -> Result<PathSegmentInfo> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let Some(info) = pnr_info.get_path_segment(path, segment) else {
+            bail!("Critical path {path} has no segment {segment}");
+        };
+
+        let netlist = pnr_info.get_netlist();
+        let mut marked: Vec<DecalPointer> = [&info.from.cell, &info.to.cell]
+            .into_iter()
+            .filter_map(|c| netlist.find_cell(c))
+            .map(|c| (ElementType::Bel, netlist.cells[c].bel.clone()))
+            .unique()
+            .collect();
+        if let Some(net) = &info.net {
+            marked.extend(self.get_net_decals(pnr_info, net));
+        }
+
+        self.route_playback.clear();
+        self.path_cursor = Some((path, segment));
+        self.zoom_to_decals(&marked);
+        self.set_marked(marked);
+        self.render()?;
+
+        Ok(info)
+    }
+
+    fn set_overlay(&mut self, elements: &[GraphicElement]) -> Result<()> {
+        let items = elements.iter().map(|g| (&ElementType::Group, "", g));
+        let (overlay, _, _) = self.to_webgl_elements(items, None)?;
+        self.overlay = overlay;
+        Ok(())
+    }
+
+    /// Draws the global clock quadrants, spines and taps, highlighting the ones used by nets
+    /// driven from a global clock buffer.
+    pub fn show_clock_network(&mut self) -> Result<ClockNetworkUsage> {
+        let network = self.architecture.get_clock_network();
+
+        let mut nets = vec![];
+        let mut used_spines: FxHashSet<(i32, i32)> = FxHashSet::default();
+        let mut used_taps: FxHashSet<usize> = FxHashSet::default();
+        if let Some(pnr_info) = &self.pnr_info {
+            let netlist = pnr_info.get_netlist();
+            for net in &netlist.nets {
+                let drivers: Vec<String> = net
+                    .driver
+                    .iter()
+                    .map(|d| &netlist.cells[d.cell])
+                    .filter(|c| self.architecture.is_global_buffer(&c.r#type))
+                    .map(|c| c.bel.clone())
+                    .collect();
+                if drivers.is_empty() {
+                    continue;
+                }
+
+                let mut spines = FxHashSet::default();
+                let mut taps = FxHashSet::default();
+                for wire in pnr_info.get_net_wires(&net.name) {
+                    match self.architecture.get_clock_wire(&wire) {
+                        Some(ClockWire::Spine { x, y }) => {
+                            spines.insert((x, y));
+                        }
+                        Some(ClockWire::Tap { x, y, left }) => {
+                            taps.extend(network.taps.iter().position(|t| {
+                                t.x == x && t.left == left && (t.y1..=t.y2).contains(&y)
+                            }));
+                        }
+                        None => {}
+                    }
+                }
+
+                nets.push(GlobalClockUsage {
+                    net: net.name.clone(),
+                    drivers,
+                    spines: spines
+                        .iter()
+                        .sorted()
+                        .map(|(x, y)| format!("X{x}/Y{y}"))
+                        .collect(),
+                    taps: taps.len(),
+                });
+                used_spines.extend(spines);
+                used_taps.extend(taps);
+            }
+        }
+
+        let style = |used: bool| {
+            if used {
+                (Style::Active, Some(self.colors.highlight))
+            } else {
+                (Style::Inactive, None)
+            }
+        };
+
+        let mut overlay = vec![];
+        for quad in &network.quadrants {
+            let mut el = GraphicElement::new(Type::Box, Style::Frame);
+            el.x1 = quad.x1 as f64;
+            el.y1 = quad.y1 as f64;
+            el.x2 = (quad.x2 + 1) as f64;
+            el.y2 = (quad.y2 + 1) as f64;
+            overlay.push(el);
+        }
+        for (i, tap) in network.taps.iter().enumerate() {
+            let (style, color) = style(used_taps.contains(&i));
+            // Offset left and right taps of a column so both stay visible
+            let x = tap.x as f64 + if tap.left { 0.4 } else { 0.6 };
+            let mut el = GraphicElement::new(Type::Line, style);
+            el.x1 = x;
+            el.y1 = tap.y1 as f64;
+            el.x2 = x;
+            el.y2 = (tap.y2 + 1) as f64;
+            el.color = color;
+            overlay.push(el);
+        }
+        for spine in &network.spines {
+            let (style, color) = style(used_spines.contains(&(spine.x, spine.y)));
+            let mut el = GraphicElement::new(Type::FilledBox, style);
+            el.x1 = spine.x as f64 + 0.25;
+            el.y1 = spine.y as f64 + 0.25;
+            el.x2 = spine.x as f64 + 0.75;
+            el.y2 = spine.y as f64 + 0.75;
+            el.color = color;
+            overlay.push(el);
+        }
+
+        self.set_overlay(&overlay)?;
+        self.render()?;
+
+        Ok(ClockNetworkUsage {
+            spines: network.spines.len(),
+            taps: network.taps.len(),
+            nets,
+        })
+    }
+
+    pub fn clear_overlay(&mut self) -> Result<()> {
+        self.overlay.clear();
+        self.render()
+    }
+
+    /// Shades each tile or switchbox by the share of its pips or wires used by the routing,
+    /// whichever is higher, relative to the most congested one. Returns the usage of every
+    /// region with routing, busiest first.
+    pub fn show_congestion(&mut self) -> Result<Vec<RegionUsage>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+
+        let regions = self.architecture.get_routing_regions();
+        let mut used_pips = vec![0u32; regions.len()];
+        for decal in self
+            .decals
+            .get(&ElementType::Pip)
+            .into_iter()
+            .flat_map(|m| m.values())
+        {
+            if let Some(u) = self
+                .architecture
+                .get_pip_region(&decal.decal)
+                .and_then(|r| used_pips.get_mut(r))
+            {
+                *u += 1;
+            }
+        }
+
+        let mut used_wires = vec![0u32; regions.len()];
+        let wire_decals = self.decals.get(&ElementType::Wire);
+        for wire in pnr_info.get_elements().wires {
+            if let Some(u) = wire_decals
+                .and_then(|m| m.get(&wire))
+                .and_then(|d| self.architecture.get_wire_region(&d.decal))
+                .and_then(|r| used_wires.get_mut(r))
+            {
+                *u += 1;
+            }
+        }
+
+        let share = |used: u32, available: u32| {
+            if available > 0 {
+                used as f32 / available as f32
+            } else {
+                0.0
+            }
+        };
+        let usage: Vec<(usize, RegionUsage)> = regions
+            .iter()
+            .zip(used_pips.into_iter().zip(used_wires))
+            .enumerate()
+            .filter(|(_, (_, (pips, wires)))| *pips > 0 || *wires > 0)
+            .map(|(i, (region, (pips, wires)))| {
+                let usage = RegionUsage {
+                    name: region.name.clone(),
+                    used: pips,
+                    available: region.pips,
+                    wires_used: wires,
+                    wires_available: region.wires,
+                    usage: share(pips, region.pips).max(share(wires, region.wires)),
+                };
+                (i, usage)
+            })
+            .filter(|(_, u)| u.usage > 0.0)
+            .sorted_by(|(_, a), (_, b)| b.usage.total_cmp(&a.usage))
+            .collect();
+
+        let max_usage = usage.first().map(|(_, u)| u.usage).unwrap_or(1.0);
+        let overlay: Vec<GraphicElement> = usage
+            .iter()
+            .map(|(i, u)| {
+                let region = &regions[*i];
+                let mut el = GraphicElement::new(Type::FilledBox, Style::Active);
+                el.x1 = region.x1;
+                el.y1 = region.y1;
+                el.x2 = region.x2;
+                el.y2 = region.y2;
+                el.color = Some(
+                    self.colors
+                        .background
+                        .mix(&self.colors.critical, u.usage / max_usage),
+                );
+                el
+            })
+            .collect();
+
+        self.set_overlay(&overlay)?;
+        self.render()?;
+
+        Ok(usage.into_iter().map(|(_, u)| u).collect())
+    }
+
+    /// Shades each `bin_size`×`bin_size` block of tiles by the fraction of its BELs that are
+    /// occupied, optionally only counting BELs of one type (e.g. `TRELLIS_COMB`).
+    pub fn show_utilization(
+        &mut self,
+        bel_type: Option<&str>,
+        bin_size: u32,
+    ) -> Result<UtilizationSummary> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let netlist = pnr_info.get_netlist();
+        let bin_size = bin_size.max(1) as i32;
+
+        let mut bins: FxHashMap<(i32, i32), (u32, u32)> = FxHashMap::default();
+        for decal in self
+            .decals
+            .get(&ElementType::Bel)
+            .into_iter()
+            .flat_map(|m| m.values())
+        {
+            let Some(site) = self.architecture.get_bel_site(&decal.decal) else {
+                continue;
+            };
+            if bel_type.is_some_and(|t| t != site.r#type) {
+                continue;
+            }
+
+            let bin = bins
+                .entry((
+                    site.x.div_euclid(bin_size) * bin_size,
+                    site.y.div_euclid(bin_size) * bin_size,
+                ))
+                .or_default();
+            if netlist.find_cell_by_bel(&decal.id).is_some() {
+                bin.0 += 1;
+            }
+            bin.1 += 1;
+        }
+
+        let bins: Vec<BinUtilization> = bins
+            .into_iter()
+            .map(|((x, y), (used, available))| BinUtilization {
+                x,
+                y,
+                used,
+                available,
+            })
+            .sorted_by_key(|b| (b.y, b.x))
+            .collect();
+
+        let report = bel_type.and_then(|t| pnr_info.get_utilization(t)).cloned();
+
+        let overlay: Vec<GraphicElement> = bins
+            .iter()
+            .filter(|b| b.used > 0)
+            .map(|b| {
+                let mut el = GraphicElement::new(Type::FilledBox, Style::Active);
+                el.x1 = b.x as f64;
+                el.y1 = b.y as f64;
+                el.x2 = (b.x + bin_size) as f64;
+                el.y2 = (b.y + bin_size) as f64;
+                el.color = Some(
+                    self.colors
+                        .background
+                        .mix(&self.colors.critical, b.used as f32 / b.available as f32),
+                );
+                el
+            })
+            .collect();
+
+        self.set_overlay(&overlay)?;
+        self.render()?;
+
+        Ok(UtilizationSummary {
+            used: bins.iter().map(|b| b.used).sum(),
+            available: bins.iter().map(|b| b.available).sum(),
+            report,
+            bins,
+        })
+    }
+
+    /// Colors every sequential cell and its clock net by clock domain.
+    pub fn show_clock_domains(&mut self) -> Result<Vec<ClockDomain>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let netlist = pnr_info.get_netlist();
+
+        let mut domains = vec![];
+        let mut marked = vec![];
+        for (i, (net, cells)) in netlist.get_clock_domains().into_iter().enumerate() {
+            let color = PALETTE[i % PALETTE.len()];
+            let net_name = &netlist.nets[net].name;
+
+            let mut decals = self.get_net_decals(pnr_info, net_name);
+            decals.extend(
+                cells
+                    .iter()
+                    .map(|&c| (ElementType::Bel, netlist.cells[c].bel.clone())),
+            );
+            marked.push((color, decals));
+
+            domains.push(ClockDomain {
+                net: net_name.clone(),
+                color,
+                cells: cells.len(),
+                fmax: pnr_info.get_fmax(net_name).cloned(),
+            });
+        }
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.mark(marked);
+        self.render()?;
+
+        Ok(domains)
+    }
+
+    pub fn get_clock_crossings(&self) -> Result<Vec<ClockCrossingInfo>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let netlist = pnr_info.get_netlist();
+
+        Ok(netlist
+            .get_clock_crossings()
+            .iter()
+            .map(|c| netlist.get_clock_crossing_info(c))
+            .collect())
+    }
+
+    /// Highlights every clock domain crossing path, including its source and destination cells.
+    pub fn show_clock_crossings(&mut self) -> Result<Vec<ClockCrossingInfo>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let netlist = pnr_info.get_netlist();
+
+        let mut infos = vec![];
+        let mut decals = vec![];
+        let mut seen_nets = FxHashSet::default();
+        for crossing in netlist.get_clock_crossings() {
+            for &net in &crossing.nets {
+                if seen_nets.insert(net) {
+                    decals.extend(self.get_net_decals(pnr_info, &netlist.nets[net].name));
+                }
+            }
+            for cell in [crossing.from, crossing.to] {
+                decals.push((ElementType::Bel, netlist.cells[cell].bel.clone()));
+            }
+            infos.push(netlist.get_clock_crossing_info(&crossing));
+        }
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.mark(vec![(CLOCK_CROSSING_COLOR, decals)]);
+        self.render()?;
+
+        Ok(infos)
+    }
+
+    pub fn get_speed_grades(&self) -> Vec<String> {
+        self.architecture.get_speed_grades()
+    }
+
+    /// The given speed grade if the architecture knows it, otherwise the first one.
+    fn resolve_speed(&self, speed: Option<&str>) -> Result<String> {
+        let grades = self.architecture.get_speed_grades();
+        if grades.is_empty() {
+            bail!("The chipdb has no timing data");
+        }
+        let speed = match speed {
+            Some(speed) => speed.to_string(),
+            None => grades.first().cloned().unwrap_or_default(),
+        };
+        if !grades.contains(&speed) {
+            bail!("Unknown speed grade {speed}");
+        }
+        Ok(speed)
+    }
+
+    /// Intrinsic delay of a routed wire and the pip driving it, in picoseconds.
+    fn route_node_delay(&self, tree: &RoutingTree, node: &RouteNode, speed: &str) -> i32 {
+        let delay = |element_type: ElementType, id: &String| {
+            let decal = self.decals.get(&element_type)?.get(id)?;
+            self.architecture
+                .get_decal_delays(&decal.decal)
+                .into_iter()
+                .find(|d| d.speed == speed)
+        };
+
+        let fanout =
+            node.parent
+                .map_or(0, |p| tree.nodes[p].children.len().saturating_sub(1)) as i32;
+        let pip = node
+            .pip
+            .as_ref()
+            .and_then(|p| self.routed_pips.get(&p.name))
+            .and_then(|id| delay(ElementType::Pip, id))
+            .map_or(0, |d| d.max + d.fanout_adder * fanout);
+        let wire = delay(ElementType::Wire, &node.wire).map_or(0, |d| d.max);
+
+        pip + wire
+    }
+
+    fn delay_model<'r>(
+        &'r self,
+        pnr_info: &'r PnrInfo,
+        speed: String,
+    ) -> RoutedDelayModel<'r, DecalID> {
+        let netlist = pnr_info.get_netlist();
+        let sinks = netlist
+            .nets
+            .iter()
+            .map(|net| {
+                let Some(tree) = pnr_info.get_routing_tree(&net.name) else {
+                    return vec![];
+                };
+                let node_delays: Vec<i32> = tree
+                    .nodes
+                    .iter()
+                    .map(|node| self.route_node_delay(&tree, node, &speed))
+                    .collect();
+
+                tree.sinks()
+                    .map(|sink| {
+                        let delay: i32 = tree.path_to(sink).iter().map(|&n| node_delays[n]).sum();
+                        (tree.nodes[sink].wire.clone(), delay as f32 / 1000.0)
+                    })
+                    .collect()
+            })
+            .collect();
+        let pin_wires = netlist
+            .cells
+            .iter()
+            .map(|cell| {
+                self.lookup_decal(&ElementType::Bel, &cell.bel)
+                    .map(|bel| {
+                        self.architecture
+                            .get_bel_pins(&bel.decal)
+                            .into_iter()
+                            .map(|p| (p.pin, p.wire.id))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        RoutedDelayModel {
+            architecture: self.architecture.as_ref(),
+            speed,
+            sinks,
+            pin_wires,
+        }
+    }
+
+    /// Estimates the routing delay from the driver of a net to each of its sink wires from the
+    /// chipdb's pip and wire timing, for a speed grade (the first one by default).
+    /// Sinks are sorted slowest first.
+    pub fn get_net_delay(&self, net: &String, speed: Option<&str>) -> Result<NetDelay> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+        let Some(index) = pnr_info.get_netlist().find_net(net) else {
+            bail!("Net {net} does not exist");
+        };
+
+        let model = self.delay_model(pnr_info, self.resolve_speed(speed)?);
+        let sinks = model.sinks[index]
+            .iter()
+            .map(|(wire, delay)| SinkDelay {
+                wire: wire.clone(),
+                delay: *delay,
+            })
+            .sorted_by(|a, b| b.delay.total_cmp(&a.delay))
+            .collect();
+
+        Ok(NetDelay {
+            net: net.clone(),
+            sinks,
+        })
+    }
+
+    /// Estimates the `count` slowest register-to-register paths of the routed design without a
+    /// timing report, see `Netlist::estimate_paths`.
+    pub fn estimate_paths(&self, count: usize, speed: Option<&str>) -> Result<Vec<EstimatedPath>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+
+        let model = self.delay_model(pnr_info, self.resolve_speed(speed)?);
+        Ok(pnr_info.get_netlist().estimate_paths(&model, count))
+    }
+
+    /// Re-estimates the critical paths of the timing report, keeping nextpnr's numbers next to
+    /// the estimates for comparison.
+    pub fn estimate_critical_paths(&self, speed: Option<&str>) -> Result<Vec<EstimatedPath>> {
+        let Some(pnr_info) = &self.pnr_info else {
+            bail!("No design loaded");
+        };
+
+        let model = self.delay_model(pnr_info, self.resolve_speed(speed)?);
+        let Some(paths) = pnr_info.estimate_critical_paths(&model) else {
+            bail!("No timing report loaded");
+        };
+        Ok(paths)
+    }
+
+    /// Colors the routed pips by their maximum intrinsic delay for a speed grade (the first one
+    /// by default), from the `active` color for the fastest to `critical` for the slowest.
+    /// Returns the pips, slowest first.
+    pub fn show_pip_delays(&mut self, speed: Option<&str>) -> Result<Vec<PipDelay>> {
+        if self.pnr_info.is_none() {
+            bail!("No design loaded");
+        }
+
+        let speed = self.resolve_speed(speed)?;
+        let delays: Vec<PipDelay> = self
+            .decals
+            .get(&ElementType::Pip)
+            .into_iter()
+            .flat_map(|m| m.iter())
+            .filter_map(|(id, decal)| {
+                let delay = self
+                    .architecture
+                    .get_decal_delays(&decal.decal)
+                    .into_iter()
+                    .find(|d| d.speed == speed)?;
+                Some(PipDelay {
+                    pip: id.clone(),
+                    delay: delay.max,
+                })
+            })
+            .sorted_by(|a, b| b.delay.cmp(&a.delay).then_with(|| a.pip.cmp(&b.pip)))
+            .collect();
+
+        let (min, max) = delays
+            .iter()
+            .map(|d| d.delay)
+            .minmax()
+            .into_option()
+            .unwrap_or((0, 0));
+        let mut steps: Vec<Vec<DecalPointer>> = vec![vec![]; DELAY_COLOR_STEPS];
+        for d in &delays {
+            let t = (d.delay - min) as f32 / (max - min).max(1) as f32;
+            let step = (t * (DELAY_COLOR_STEPS - 1) as f32).round() as usize;
+            steps[step].push((ElementType::Pip, d.pip.clone()));
+        }
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        let marked = steps
+            .into_iter()
+            .enumerate()
+            .filter(|(_, decals)| !decals.is_empty())
+            .map(|(step, decals)| {
+                let t = step as f32 / (DELAY_COLOR_STEPS - 1) as f32;
+                (self.colors.active.mix(&self.colors.critical, t), decals)
+            })
+            .collect();
+        self.mark(marked);
+        self.render()?;
+
+        Ok(delays)
+    }
+
+    pub fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        self.architecture.get_tile_names(x, y)
+    }
+
+    pub fn get_packages(&self) -> Vec<String> {
+        self.architecture.get_packages()
+    }
+
+    fn to_package_pin_info(&self, pin: PackagePin) -> PackagePinInfo {
+        let cell = self.pnr_info.as_ref().and_then(|p| {
+            let netlist = p.get_netlist();
+            netlist
+                .find_cell_by_bel(&pin.bel)
+                .map(|c| &netlist.cells[c])
+        });
+
+        PackagePinInfo {
+            pin: pin.name,
+            bel: pin.bel,
+            function: pin.function,
+            bank: pin.bank,
+            dqs_group: pin.dqs_group,
+            cell: cell.map(|c| c.name.clone()),
+            io_standard: cell.and_then(|c| c.io_standard.clone()),
+        }
+    }
+
+    /// Lists the bonded pins of a package, together with the design IO placed on each.
+    pub fn get_package_pins(&self, package: &str) -> Result<Vec<PackagePinInfo>> {
+        let pins = self.architecture.get_package_pins(package);
+        if pins.is_empty() {
+            bail!("Unknown package {package}");
+        }
+
+        Ok(pins
+            .into_iter()
+            .map(|p| self.to_package_pin_info(p))
+            .collect())
+    }
+
+    /// Package pin bonded to an IO BEL, used to label it.
+    pub fn get_bel_package_pin(&self, package: &str, bel: &str) -> Option<PackagePinInfo> {
+        self.architecture
+            .get_package_pins(package)
+            .into_iter()
+            .find(|p| p.bel == bel)
+            .map(|p| self.to_package_pin_info(p))
+    }
+
+    /// Highlights the IO BELs bonded in a package, design IOs in a stronger color.
+    pub fn show_package_pins(&mut self, package: &str) -> Result<Vec<PackagePinInfo>> {
+        let pins = self.get_package_pins(package)?;
+
+        let (assigned, unassigned): (Vec<_>, Vec<_>) = pins.iter().partition(|p| p.cell.is_some());
+        let to_decals = |pins: Vec<&PackagePinInfo>| {
+            pins.into_iter()
+                .map(|p| (ElementType::Bel, p.bel.clone()))
+                .collect()
+        };
+
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.mark(vec![
+            (self.colors.active, to_decals(unassigned)),
+            (self.colors.highlight, to_decals(assigned)),
+        ]);
+        self.render()?;
+
+        Ok(pins)
+    }
+
+    /// Draws the footprint of a package instead of the die. Balls are filled with their bank's
+    /// color, dimmed when no design IO is placed on them, and select their IO BEL when picked.
+    pub fn show_package_view(&mut self, package: &str) -> Result<Vec<PackagePinInfo>> {
+        let pins = self.get_package_pins(package)?;
+        let names: Vec<&str> = pins.iter().map(|p| p.pin.as_str()).collect();
+        let positions = gfx::package::pin_positions(&names);
+
+        let mut elements = vec![];
+        let mut balls = vec![];
+        for (pin, &(x, y)) in pins.iter().zip(&positions) {
+            let bank_color = pin.bank.map_or(self.colors.inactive, |b| {
+                PALETTE[b.rem_euclid(PALETTE.len() as i32) as usize]
+            });
+
+            let mut el = GraphicElement::new(Type::FilledBox, Style::Active);
+            el.x1 = x + 0.15;
+            el.y1 = y + 0.15;
+            el.x2 = x + 0.85;
+            el.y2 = y + 0.85;
+            el.color = Some(if pin.cell.is_some() {
+                bank_color
+            } else {
+                self.colors.background.mix(&bank_color, 0.3)
+            });
+            elements.push(el);
+
+            balls.push(PackageBall {
+                x,
+                y,
+                bel: pin.bel.clone(),
+            });
+        }
+
+        let items = elements.iter().map(|g| (&ElementType::Group, "", g));
+        let (elements, _, _) = self.to_webgl_elements(items, None)?;
+        self.package_view = Some(PackageView { balls, elements });
+
+        let (min_x, max_x) = positions
+            .iter()
+            .map(|p| p.0)
+            .minmax()
+            .into_option()
+            .unwrap_or((0.0, 0.0));
+        let (min_y, max_y) = positions
+            .iter()
+            .map(|p| p.1)
+            .minmax()
+            .into_option()
+            .unwrap_or((0.0, 0.0));
+        self.zoom_to_bounds(
+            min_x as f32,
+            max_x as f32 + 1.0,
+            min_y as f32,
+            max_y as f32 + 1.0,
+        );
+        self.render()?;
+
+        Ok(pins)
+    }
+
+    /// Switches back from the package view to the die, zooming to the selected decal.
+    pub fn show_die_view(&mut self) -> Result<()> {
+        if self.package_view.take().is_some() {
+            if let Some(selected) = self.selection.selected.clone() {
+                self.zoom_to_decals(&[selected]);
+            }
+        }
+        self.render()
+    }
+
+    pub fn clear_highlight(&mut self) -> Result<()> {
+        self.mark(vec![]);
+        self.route_playback.clear();
+        self.path_cursor = None;
+        self.render()
+    }
+
+    pub fn cancel_selection(&mut self, is_full_select: bool) -> Result<()> {
+        if is_full_select {
+            self.selection.selected = None;
+        } else {
+            self.selection.highlighted = None;
+        }
+
+        self.render()?;
+
+        Ok(())
+    }
+
+    fn canvas_to_world(&self, x: f32, y: f32) -> (f32, f32) {
+        let wx = (x / self.scale) + self.offset.0;
+        // Don't flip Y - the vertex shader handles Y-axis transformation
+        let wy = -(y / self.scale) - self.offset.1;
+        (wx, wy)
+    }
+
+    pub fn select_decal_at_world(
+        &mut self,
+        x: f32,
+        y: f32,
+        is_full_select: bool,
+    ) -> Result<Option<DecalPointer>> {
+        if let Some(view) = &self.package_view {
+            let ball = view.balls.iter().find(|b| {
+                (b.x..b.x + 1.0).contains(&(x as f64)) && (b.y..b.y + 1.0).contains(&(y as f64))
+            });
+            match ball {
+                Some(ball) => {
+                    let bel = ball.bel.clone();
+                    self.select_decal(ElementType::Bel, &bel, false, is_full_select)?
+                }
+                None => self.cancel_selection(is_full_select)?,
+            }
+
+            return Ok(if is_full_select {
+                self.selection.selected.clone()
+            } else {
+                self.selection.highlighted.clone()
+            });
+        }
+
+        let Some(rtree) = &self.rtree else {
+            return Ok(None);
+        };
+        let rtree_data = &self.rtree_data;
+
+        let selection = {
+            let line_dist = |p1: [f32; 2], p2: [f32; 2], point: [f32; 2]| {
+                let line_vec = [p2[0] - p1[0], p2[1] - p1[1]];
+                let line_len_2 = line_vec[0] * line_vec[0] + line_vec[1] * line_vec[1];
+                let t = if line_len_2 > 0.0 {
+                    ((point[0] - p1[0]) * line_vec[0] + (point[1] - p1[1]) * line_vec[1])
+                        / line_len_2
+                } else {
+                    0.0
+                };
+                let t_clamped = t.clamp(0.0, 1.0);
+                let closest = [
+                    p1[0] + t_clamped * line_vec[0],
+                    p1[1] + t_clamped * line_vec[1],
+                ];
+                ((point[0] - closest[0]) * (point[0] - closest[0])
+                    + (point[1] - closest[1]) * (point[1] - closest[1]))
+                    .sqrt()
+            };
+            let score = |etype: ElementType, decal_id: &str| -> (u8, f32) {
+                let mut best_line: Option<f32> = None;
+                let mut best_rect: Option<f32> = None;
+
+                if let Some(ge_vec) = self.decal_graphics(&etype, decal_id) {
+                    for ge in ge_vec {
+                        match ge.r#type {
+                            Type::Line | Type::Arrow | Type::LocalLine | Type::LocalArrow => {
+                                let d = line_dist(
+                                    [ge.x1 as f32, ge.y1 as f32],
+                                    [ge.x2 as f32, ge.y2 as f32],
+                                    [x, y],
+                                );
+                                best_line = Some(best_line.map_or(d, |best| best.min(d)));
+                            }
+                            Type::Box | Type::FilledBox => {
+                                let (minx, maxx) = (
+                                    (ge.x1 as f32).min(ge.x2 as f32),
+                                    (ge.x1 as f32).max(ge.x2 as f32),
+                                );
+                                let (miny, maxy) = (
+                                    (ge.y1 as f32).min(ge.y2 as f32),
+                                    (ge.y1 as f32).max(ge.y2 as f32),
+                                );
+                                let area = (maxx - minx).abs() * (maxy - miny).abs();
+                                best_rect = Some(best_rect.map_or(area, |best| best.min(area)));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                if let Some(d) = best_line {
+                    if d <= PICK_EPSILON {
+                        return (0, d);
+                    }
+                    if let Some(area) = best_rect {
+                        return (1, area);
+                    }
+                    return (2, d);
+                }
+
+                if let Some(area) = best_rect {
+                    return (1, area);
+                }
+
+                (3, 0.0)
+            };
+
+            rtree
+                .locate_all_at_point(&[x, y])
+                .map(|entry| {
+                    rtree_data
+                        .get(&entry.data)
+                        .expect("RTree data should be in sync")
+                        .clone()
+                })
+                .min_by(|a, b| {
+                    let sa = score(a.0, &a.1);
+                    let sb = score(b.0, &b.1);
+
+                    sa.0.cmp(&sb.0).then_with(|| sa.1.total_cmp(&sb.1))
+                })
+        };
+
+        if let Some((etype, decal_id)) = selection {
+            self.select_decal(etype, &decal_id, false, is_full_select)?;
+        } else {
+            self.cancel_selection(is_full_select)?;
+        }
+
+        if is_full_select {
+            Ok(self.selection.selected.clone())
+        } else {
+            Ok(self.selection.highlighted.clone())
+        }
+    }
+
+    pub fn select_decal_at_canvas(
+        &mut self,
+        x: f32,
+        y: f32,
+        is_full_select: bool,
+    ) -> Result<Option<DecalPointer>> {
+        let (wx, wy) = self.canvas_to_world(x, y);
+        self.select_decal_at_world(wx, wy, is_full_select)
+    }
+}
+
+fn bel_pin_info<DecalID>(netlist: Option<&Netlist>, pin: BelPin<DecalID>) -> BelPinInfo {
+    let net = netlist.and_then(|n| {
+        n.find_net_by_wire(&pin.wire.id)
+            .map(|net| n.nets[net].name.clone())
+    });
+
+    BelPinInfo {
+        bel: pin.bel.id,
+        pin: pin.pin,
+        direction: pin.direction,
+        wire: pin.wire.id,
+        net,
+    }
+}
+
+/// Maximum delay of a decal for a speed grade, zero if it has none.
+fn max_delay<DecalID>(
+    architecture: &dyn Architecture<DecalID>,
+    decal: &DecalID,
+    speed: &str,
+) -> i32 {
+    architecture
+        .get_decal_delays(decal)
+        .into_iter()
+        .find(|d| d.speed == speed)
+        .map_or(0, |d| d.max)
+}
+
+/// Dijkstra search downhill from `from` until `to` is reached. Returns the wires along the
+/// way, each with the pip driving it, and the number of wires expanded.
+fn search_route<DecalID: Clone>(
+    architecture: &dyn Architecture<DecalID>,
+    from: DecalXY<DecalID>,
+    to: &str,
+    cost: RouteCost,
+    speed: &str,
+) -> Result<(Vec<SearchStep<DecalID>>, u32)> {
+    let from_id = from.id.clone();
+    let mut by_id: FxHashMap<String, usize> = FxHashMap::from_iter([(from.id.clone(), 0)]);
+    let mut nodes = vec![SearchNode {
+        wire: from,
+        via: None,
+        cost: 0,
+    }];
+    let mut queue = BinaryHeap::from([Reverse((0i64, 0usize))]);
+    let mut visited = 0usize;
+
+    while let Some(Reverse((node_cost, i))) = queue.pop() {
+        if node_cost > nodes[i].cost {
+            continue;
+        }
+
+        if nodes[i].wire.id == to {
+            let mut path = vec![];
+            let mut current = Some(i);
+            while let Some(c) = current {
+                let node = &nodes[c];
+                path.push((
+                    node.via.as_ref().map(|(_, pip)| pip.clone()),
+                    node.wire.clone(),
+                ));
+                current = node.via.as_ref().map(|(prev, _)| *prev);
+            }
+            path.reverse();
+            return Ok((path, visited as u32));
+        }
+
+        visited += 1;
+        if visited > ROUTE_SEARCH_LIMIT {
+            bail!("No route from {from_id} to {to} within {ROUTE_SEARCH_LIMIT} wires");
+        }
+
+        for pip in architecture.get_pips_downhill(&nodes[i].wire.decal) {
+            let Some((_, dst)) = architecture.get_pip_wires(&pip.decal) else {
+                continue;
+            };
+            let step = match cost {
+                RouteCost::Hops => 1,
+                RouteCost::Delay => {
+                    max_delay(architecture, &pip.decal, speed)
+                        + max_delay(architecture, &dst.decal, speed)
+                }
+            };
+            let next_cost = node_cost + step as i64;
+
+            let j = match by_id.get(&dst.id) {
+                Some(&j) if nodes[j].cost <= next_cost => continue,
+                Some(&j) => {
+                    nodes[j].cost = next_cost;
+                    nodes[j].via = Some((i, pip));
+                    j
+                }
+                None => {
+                    by_id.insert(dst.id.clone(), nodes.len());
+                    nodes.push(SearchNode {
+                        wire: dst,
+                        via: Some((i, pip)),
+                        cost: next_cost,
+                    });
+                    nodes.len() - 1
+                }
+            };
+            queue.push(Reverse((next_cost, j)));
+        }
+    }
+
+    bail!("No route from {from_id} to {to}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::architecture::MachXO2Arch;
+    use crate::chipdb::{self, machxo2::fixture};
+    use crate::decal::ECP5DecalID;
+
+    fn arch() -> MachXO2Arch {
+        let const_ids = fixture::CONST_IDS.map(str::to_string);
+        let db = chipdb::machxo2::get_full_chipinfo(&fixture::chipdb(), &const_ids).unwrap();
+        let min: chipdb::machxo2::MinimizedChipDatabase = db.into();
+        let encoded = chipdb::encode_min_chipinfo(min).unwrap();
+        MachXO2Arch::new(chipdb::decode_min_chipinfo(&encoded).unwrap())
+    }
+
+    fn wire(arch: &MachXO2Arch, id: &str) -> DecalXY<ECP5DecalID> {
+        arch.get_wire_decals()
+            .into_iter()
+            .find(|w| w.id == id)
+            .unwrap()
+    }
+
+    fn ids<DecalID>(path: &[SearchStep<DecalID>]) -> Vec<(Option<&str>, &str)> {
+        path.iter()
+            .map(|(pip, wire)| (pip.as_ref().map(|p| p.id.as_str()), wire.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn searches_routes_downhill_through_pips() {
+        let arch = arch();
+        let from = wire(&arch, "X0/Y0/F0_SLICE");
+
+        let (path, visited) =
+            search_route(&arch, from.clone(), "X1/Y0/A0_SLICE", RouteCost::Hops, "1").unwrap();
+        assert_eq!(
+            ids(&path),
+            [
+                (None, "X0/Y0/F0_SLICE"),
+                (
+                    Some("X1/Y0;X-1/Y0/F0_SLICE->X0/Y0/A0_SLICE"),
+                    "X1/Y0/A0_SLICE"
+                ),
+            ]
+        );
+        assert_eq!(visited, 1);
+
+        let (path, _) =
+            search_route(&arch, from.clone(), "X1/Y0/A0_SLICE", RouteCost::Delay, "1").unwrap();
+        let pip = path[1].0.as_ref().unwrap();
+        assert_eq!(max_delay(&arch, &pip.decal, "1"), 70);
+        assert_eq!(max_delay(&arch, &pip.decal, "2"), 60);
+
+        let (path, visited) =
+            search_route(&arch, from, "X0/Y0/F0_SLICE", RouteCost::Hops, "1").unwrap();
+        assert_eq!(ids(&path), [(None, "X0/Y0/F0_SLICE")]);
+        assert_eq!(visited, 0);
+    }
+
+    #[test]
+    fn fails_without_a_downhill_route() {
+        let arch = arch();
+        let from = wire(&arch, "X1/Y0/A0_SLICE");
+
+        let Err(err) = search_route(&arch, from, "X0/Y0/F0_SLICE", RouteCost::Hops, "1") else {
+            panic!("A0_SLICE drives no pips");
+        };
+        assert_eq!(
+            err.to_string(),
+            "No route from X1/Y0/A0_SLICE to X0/Y0/F0_SLICE"
+        );
     }
 }
@@ -1,8 +1,10 @@
-import { ViewerECP5, ViewerICE40 } from "../pkg/nextpnr_renderer";
+import { ViewerECP5, ViewerICE40, ViewerMachXO2, ViewerNexus } from "../pkg/nextpnr_renderer";
 
 export const VIEWERS = <const> {
     ecp5: ViewerECP5,
     ice40: ViewerICE40,
+    machxo2: ViewerMachXO2,
+    nexus: ViewerNexus,
 };
 
 export type SupportedFamily = keyof typeof VIEWERS;
@@ -21,6 +23,18 @@ const CHIP_DBS = <const> {
         "5k": new URL(`../static/chipdb/ice40/5k-min.bin`, import.meta.url),
         "8k": new URL(`../static/chipdb/ice40/8k-min.bin`, import.meta.url),
     },
+    machxo2: {
+        "256": new URL(`../static/chipdb/machxo2/256-min.bin`, import.meta.url),
+        "640": new URL(`../static/chipdb/machxo2/640-min.bin`, import.meta.url),
+        "1200": new URL(`../static/chipdb/machxo2/1200-min.bin`, import.meta.url),
+        "2000": new URL(`../static/chipdb/machxo2/2000-min.bin`, import.meta.url),
+        "4000": new URL(`../static/chipdb/machxo2/4000-min.bin`, import.meta.url),
+        "7000": new URL(`../static/chipdb/machxo2/7000-min.bin`, import.meta.url),
+    },
+    nexus: {
+        "LIFCL-17": new URL(`../static/chipdb/nexus/LIFCL-17-min.bin`, import.meta.url),
+        "LIFCL-40": new URL(`../static/chipdb/nexus/LIFCL-40-min.bin`, import.meta.url),
+    },
 } satisfies Record<SupportedFamily, Record<string, URL>>;
 
 // **** Auxiliary types ****
@@ -51,6 +65,23 @@ export const SUPPORTED_DEVICES = <const> {
         lp4k: CHIP_DBS['ice40']['8k'],
         hx4k: CHIP_DBS['ice40']['8k'],
     },
+    machxo2: {
+        "256": CHIP_DBS['machxo2']['256'],
+        "640": CHIP_DBS['machxo2']['640'],
+        "1200": CHIP_DBS['machxo2']['1200'],
+        "2000": CHIP_DBS['machxo2']['2000'],
+        "4000": CHIP_DBS['machxo2']['4000'],
+        "7000": CHIP_DBS['machxo2']['7000'],
+        "xo3-640": CHIP_DBS['machxo2']['640'],
+        "xo3-1300": CHIP_DBS['machxo2']['1200'],
+        "xo3-2100": CHIP_DBS['machxo2']['2000'],
+        "xo3-4300": CHIP_DBS['machxo2']['4000'],
+        "xo3-6900": CHIP_DBS['machxo2']['7000'],
+    },
+    nexus: {
+        "LIFCL-17": CHIP_DBS['nexus']['LIFCL-17'],
+        "LIFCL-40": CHIP_DBS['nexus']['LIFCL-40'],
+    },
 } satisfies Record<SupportedFamily, Record<string, URL>>;
 
 interface Chip<Family extends SupportedFamily> {
@@ -1,9 +1,9 @@
 use crate::{
-    architecture::{ECP5Arch, ICE40Arch},
+    architecture::{ECP5Arch, ICE40Arch, MachXO2Arch, NexusArch},
     chipdb,
-    decal::{ECP5DecalID, ICE40DecalID},
-    pnrjson::{Chip, INextpnrJSON, IReportJSON, PnrInfo},
-    renderer::{CellColorConfig, ColorConfig, Renderer},
+    decal::{ECP5DecalID, ICE40DecalID, NexusDecalID},
+    pnrjson::{Chip, ConeDirection, INextpnrJSON, IReportJSON, PathStep, PnrInfo},
+    renderer::{CellColorConfig, ColorConfig, Renderer, RouteCost},
     utils::debug_log,
     webgl::ElementType,
 };
@@ -30,6 +30,116 @@ interface ColorConfig {
 }
 
 type CellColorConfig = Record<string, Color>;
+
+interface ClockDomain {
+    net: string,
+    color: Color,
+    cells: number,
+    fmax?: Fmax,
+}
+
+interface BinUtilization {
+    x: number,
+    y: number,
+    used: number,
+    available: number,
+}
+
+interface UtilizationSummary {
+    used: number,
+    available: number,
+    report?: Utilization,
+    bins: BinUtilization[],
+}
+
+interface GlobalClockUsage {
+    net: string,
+    drivers: string[],
+    spines: string[],
+    taps: number,
+}
+
+interface ClockNetworkUsage {
+    spines: number,
+    taps: number,
+    nets: GlobalClockUsage[],
+}
+
+interface ResourceDelay {
+    speed: string,
+    min: number,
+    max: number,
+    fanout_adder: number,
+}
+
+interface PipDelay {
+    pip: string,
+    delay: number,
+}
+
+interface BelPinInfo {
+    bel: string,
+    pin: string,
+    direction: 'in' | 'out' | 'inout',
+    wire: string,
+    net?: string,
+}
+
+interface CandidateStep {
+    wire: string,
+    pip?: string,
+}
+
+interface CandidateRoute {
+    hops: number,
+    delay: number,
+    steps: CandidateStep[],
+    visited: number,
+    routed_hops?: number,
+    routed_delay?: number,
+}
+
+interface PipLink {
+    pip: string,
+    wire: string,
+    active: boolean,
+}
+
+interface WirePips {
+    wire: string,
+    net?: string,
+    uphill: PipLink[],
+    downhill: PipLink[],
+}
+
+interface SinkDelay {
+    wire: string,
+    delay: number,
+}
+
+interface NetDelay {
+    net: string,
+    sinks: SinkDelay[],
+}
+
+interface PackagePinInfo {
+    pin: string,
+    bel: string,
+    function?: string,
+    bank?: number,
+    dqs_group?: number,
+    cell?: string,
+    io_standard?: string,
+}
+
+interface RegionUsage {
+    name: string,
+    used: number,
+    available: number,
+    wires_used: number,
+    wires_available: number,
+    usage: number,
+}
 "#;
 
 #[wasm_bindgen]
@@ -145,6 +255,353 @@ impl ViewerECP5 {
             .map_err(|e| JsError::from(&*e))
     }
 
+    #[wasm_bindgen]
+    pub fn highlight_cone(
+        &mut self,
+        element_type: ElementType,
+        decal_id: &str,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Result<JsValue, JsError> {
+        let cone = self
+            .renderer
+            .highlight_cone(element_type, decal_id, direction, levels, stop_at_ff)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&cone).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_highlight(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_highlight()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_net(&self, wire: &str) -> Option<String> {
+        self.renderer.get_wire_net(wire)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_bel_pins(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_wire_bel_pins(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .get_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .show_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route(
+        &mut self,
+        from_wire: &str,
+        to_wire: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route(from_wire, to_wire, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route_to_pin(
+        &mut self,
+        from_wire: &str,
+        bel: &str,
+        pin: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route_to_pin(from_wire, bel, pin, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_route(&self, net: String) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .get_route_info(&net)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_route(
+        &mut self,
+        net: String,
+        sink_wire: Option<String>,
+    ) -> Result<(), JsError> {
+        self.renderer
+            .highlight_route(&net, sink_wire.as_deref())
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn start_route_playback(&mut self, net: String) -> Result<u32, JsError> {
+        self.renderer
+            .start_route_playback(&net)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn set_route_playback_step(&mut self, step: u32) -> Result<(), JsError> {
+        self.renderer
+            .set_route_playback_step(step)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn path_step(&mut self, step: PathStep) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .path_step(step)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_path_segment(&mut self, path: usize, segment: usize) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .select_path_segment(path, segment)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_detours(&self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .get_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_detours(&mut self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .highlight_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_congestion(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_congestion()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_utilization(
+        &mut self,
+        bel_type: Option<String>,
+        bin_size: Option<u32>,
+    ) -> Result<JsValue, JsError> {
+        let summary = self
+            .renderer
+            .show_utilization(bel_type.as_deref(), bin_size.unwrap_or(1))
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&summary).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_overlay(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_overlay()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_network(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_clock_network()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_domains(&mut self) -> Result<JsValue, JsError> {
+        let domains = self
+            .renderer
+            .show_clock_domains()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&domains).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_clock_crossings(&self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .get_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_crossings(&mut self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .show_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_speed_grades(&self) -> Vec<String> {
+        self.renderer.get_speed_grades()
+    }
+
+    #[wasm_bindgen]
+    pub fn show_pip_delays(&mut self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delays = self
+            .renderer
+            .show_pip_delays(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delays).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_net_delay(&self, net: String, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delay = self
+            .renderer
+            .get_net_delay(&net, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delay).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_paths(&self, count: usize, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_paths(count, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_critical_paths(&self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_critical_paths(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        self.renderer.get_tile_names(x, y)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_packages(&self) -> Vec<String> {
+        self.renderer.get_packages()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_package_pins(&self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_package_pin(&self, package: &str, bel: &str) -> Result<JsValue, JsError> {
+        let pin = self.renderer.get_bel_package_pin(package, bel);
+        serde_wasm_bindgen::to_value(&pin).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_pins(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_view(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_view(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_die_view(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .show_die_view()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_unused_pips(&mut self, enabled: bool) -> Result<(), JsError> {
+        self.renderer
+            .show_unused_pips(enabled)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_device_pip_ids(&self, offset: usize, count: usize) -> Vec<String> {
+        self.renderer.get_device_pip_ids(offset, count)
+    }
+
+    #[wasm_bindgen]
+    pub fn search_pips(&self, query: &str, limit: usize) -> Vec<String> {
+        self.renderer.search_pips(query, limit)
+    }
+
     #[wasm_bindgen]
     pub fn get_decal_ids(&mut self, decal_type: ElementType) -> Result<Vec<String>, JsError> {
         Ok(self.renderer.get_decal_ids(decal_type))
@@ -272,6 +729,1301 @@ impl ViewerICE40 {
             .map_err(|e| JsError::from(&*e))
     }
 
+    #[wasm_bindgen]
+    pub fn highlight_cone(
+        &mut self,
+        element_type: ElementType,
+        decal_id: &str,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Result<JsValue, JsError> {
+        let cone = self
+            .renderer
+            .highlight_cone(element_type, decal_id, direction, levels, stop_at_ff)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&cone).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_highlight(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_highlight()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_net(&self, wire: &str) -> Option<String> {
+        self.renderer.get_wire_net(wire)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_bel_pins(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_wire_bel_pins(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .get_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .show_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route(
+        &mut self,
+        from_wire: &str,
+        to_wire: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route(from_wire, to_wire, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route_to_pin(
+        &mut self,
+        from_wire: &str,
+        bel: &str,
+        pin: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route_to_pin(from_wire, bel, pin, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_route(&self, net: String) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .get_route_info(&net)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_route(
+        &mut self,
+        net: String,
+        sink_wire: Option<String>,
+    ) -> Result<(), JsError> {
+        self.renderer
+            .highlight_route(&net, sink_wire.as_deref())
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn start_route_playback(&mut self, net: String) -> Result<u32, JsError> {
+        self.renderer
+            .start_route_playback(&net)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn set_route_playback_step(&mut self, step: u32) -> Result<(), JsError> {
+        self.renderer
+            .set_route_playback_step(step)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn path_step(&mut self, step: PathStep) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .path_step(step)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_path_segment(&mut self, path: usize, segment: usize) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .select_path_segment(path, segment)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_detours(&self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .get_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_detours(&mut self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .highlight_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_congestion(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_congestion()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_utilization(
+        &mut self,
+        bel_type: Option<String>,
+        bin_size: Option<u32>,
+    ) -> Result<JsValue, JsError> {
+        let summary = self
+            .renderer
+            .show_utilization(bel_type.as_deref(), bin_size.unwrap_or(1))
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&summary).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_overlay(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_overlay()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_network(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_clock_network()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_domains(&mut self) -> Result<JsValue, JsError> {
+        let domains = self
+            .renderer
+            .show_clock_domains()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&domains).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_clock_crossings(&self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .get_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_crossings(&mut self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .show_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_speed_grades(&self) -> Vec<String> {
+        self.renderer.get_speed_grades()
+    }
+
+    #[wasm_bindgen]
+    pub fn show_pip_delays(&mut self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delays = self
+            .renderer
+            .show_pip_delays(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delays).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_net_delay(&self, net: String, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delay = self
+            .renderer
+            .get_net_delay(&net, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delay).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_paths(&self, count: usize, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_paths(count, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_critical_paths(&self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_critical_paths(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        self.renderer.get_tile_names(x, y)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_packages(&self) -> Vec<String> {
+        self.renderer.get_packages()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_package_pins(&self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_package_pin(&self, package: &str, bel: &str) -> Result<JsValue, JsError> {
+        let pin = self.renderer.get_bel_package_pin(package, bel);
+        serde_wasm_bindgen::to_value(&pin).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_pins(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_view(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_view(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_die_view(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .show_die_view()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_unused_pips(&mut self, enabled: bool) -> Result<(), JsError> {
+        self.renderer
+            .show_unused_pips(enabled)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_device_pip_ids(&self, offset: usize, count: usize) -> Vec<String> {
+        self.renderer.get_device_pip_ids(offset, count)
+    }
+
+    #[wasm_bindgen]
+    pub fn search_pips(&self, query: &str, limit: usize) -> Vec<String> {
+        self.renderer.search_pips(query, limit)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_decal_ids(&mut self, decal_type: ElementType) -> Result<Vec<String>, JsError> {
+        Ok(self.renderer.get_decal_ids(decal_type))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_decals(
+        &mut self,
+        decal_type: ElementType,
+        decal_ids: Vec<String>,
+    ) -> Result<js_sys::Map, JsError> {
+        let decal_info = self.renderer.get_decal_info(decal_type, &decal_ids);
+        let map = js_sys::Map::new();
+        for (id, info) in decal_info {
+            map.set(
+                &JsValue::from_str(&id),
+                &serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))?,
+            );
+        }
+        Ok(map)
+    }
+}
+
+#[wasm_bindgen]
+pub struct ViewerNexus {
+    renderer: Renderer<'static, NexusDecalID>,
+}
+
+#[wasm_bindgen]
+impl ViewerNexus {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        canvas: OffscreenCanvas,
+        chipdata: &[u8],
+        colors: IColorConfig,
+        cell_colors: ICellColorConfig,
+    ) -> Result<Self, JsError> {
+        debug_log("ViewerNexus::new: start".to_string());
+        let colors_conf: ColorConfig = match serde_wasm_bindgen::from_value(colors.obj) {
+            Ok(colors_conf) => colors_conf,
+            Err(e) => return Err(JsError::from(e)),
+        };
+        let cell_colors_conf: CellColorConfig =
+            match serde_wasm_bindgen::from_value(cell_colors.obj) {
+                Ok(cell_colors_conf) => cell_colors_conf,
+                Err(e) => return Err(JsError::from(e)),
+            };
+
+        let db = match chipdb::decode_min_chipinfo(chipdata) {
+            Ok(db) => db,
+            Err(e) => return Err(JsError::from(&*e)),
+        };
+
+        let arch = NexusArch::new(db);
+
+        let renderer = match Renderer::new(canvas, arch, colors_conf, cell_colors_conf) {
+            Ok(r) => r,
+            Err(e) => return Err(JsError::from(&*e)),
+        };
+
+        debug_log("ViewerNexus::new: renderer ready".to_string());
+
+        Ok(Self { renderer })
+    }
+
+    #[wasm_bindgen]
+    pub fn render(&mut self) -> Result<(), JsError> {
+        debug_log("ViewerNexus::render".to_string());
+        self.renderer.render().map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_json(
+        &mut self,
+        obj: INextpnrJSON,
+        report: Option<IReportJSON>,
+        do_render: Option<bool>,
+    ) -> Result<(), JsError> {
+        debug_log("ViewerNexus::show_json".to_string());
+        let pnr_info = PnrInfo::from_jsobj(Chip::Nexus, obj, report)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        self.renderer
+            .show_json(pnr_info, do_render.unwrap_or(true))
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn zoom(&mut self, amt: f32, x: f32, y: f32) -> Result<(), JsError> {
+        self.renderer
+            .zoom(amt, x, y)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn pan(&mut self, x: f32, y: f32) -> Result<(), JsError> {
+        self.renderer.pan(x, y).map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_at_coords(
+        &mut self,
+        x: f32,
+        y: f32,
+        only_highlight: bool,
+    ) -> Result<JsValue, JsError> {
+        let selection = self
+            .renderer
+            .select_decal_at_canvas(x, y, only_highlight)
+            .map_err(|e| JsError::from(&*e))?;
+
+        Ok(selection
+            .map(|(et, s)| {
+                let arr = js_sys::Array::new();
+                arr.push(&JsValue::from_f64(et as u8 as f64));
+                arr.push(&JsValue::from_str(&s));
+                arr.into()
+            })
+            .unwrap_or(JsValue::NULL))
+    }
+
+    #[wasm_bindgen]
+    pub fn select(&mut self, element_type: ElementType, decal_id: &str) -> Result<(), JsError> {
+        self.renderer
+            .select_decal(element_type, decal_id, true, false)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_cone(
+        &mut self,
+        element_type: ElementType,
+        decal_id: &str,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Result<JsValue, JsError> {
+        let cone = self
+            .renderer
+            .highlight_cone(element_type, decal_id, direction, levels, stop_at_ff)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&cone).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_highlight(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_highlight()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_net(&self, wire: &str) -> Option<String> {
+        self.renderer.get_wire_net(wire)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_bel_pins(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_wire_bel_pins(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .get_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .show_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route(
+        &mut self,
+        from_wire: &str,
+        to_wire: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route(from_wire, to_wire, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route_to_pin(
+        &mut self,
+        from_wire: &str,
+        bel: &str,
+        pin: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route_to_pin(from_wire, bel, pin, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_route(&self, net: String) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .get_route_info(&net)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_route(
+        &mut self,
+        net: String,
+        sink_wire: Option<String>,
+    ) -> Result<(), JsError> {
+        self.renderer
+            .highlight_route(&net, sink_wire.as_deref())
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn start_route_playback(&mut self, net: String) -> Result<u32, JsError> {
+        self.renderer
+            .start_route_playback(&net)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn set_route_playback_step(&mut self, step: u32) -> Result<(), JsError> {
+        self.renderer
+            .set_route_playback_step(step)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn path_step(&mut self, step: PathStep) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .path_step(step)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_path_segment(&mut self, path: usize, segment: usize) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .select_path_segment(path, segment)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_detours(&self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .get_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_detours(&mut self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .highlight_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_congestion(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_congestion()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_utilization(
+        &mut self,
+        bel_type: Option<String>,
+        bin_size: Option<u32>,
+    ) -> Result<JsValue, JsError> {
+        let summary = self
+            .renderer
+            .show_utilization(bel_type.as_deref(), bin_size.unwrap_or(1))
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&summary).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_overlay(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_overlay()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_network(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_clock_network()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_domains(&mut self) -> Result<JsValue, JsError> {
+        let domains = self
+            .renderer
+            .show_clock_domains()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&domains).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_clock_crossings(&self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .get_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_crossings(&mut self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .show_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_speed_grades(&self) -> Vec<String> {
+        self.renderer.get_speed_grades()
+    }
+
+    #[wasm_bindgen]
+    pub fn show_pip_delays(&mut self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delays = self
+            .renderer
+            .show_pip_delays(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delays).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_net_delay(&self, net: String, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delay = self
+            .renderer
+            .get_net_delay(&net, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delay).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_paths(&self, count: usize, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_paths(count, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_critical_paths(&self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_critical_paths(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        self.renderer.get_tile_names(x, y)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_packages(&self) -> Vec<String> {
+        self.renderer.get_packages()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_package_pins(&self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_package_pin(&self, package: &str, bel: &str) -> Result<JsValue, JsError> {
+        let pin = self.renderer.get_bel_package_pin(package, bel);
+        serde_wasm_bindgen::to_value(&pin).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_pins(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_view(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_view(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_die_view(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .show_die_view()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_unused_pips(&mut self, enabled: bool) -> Result<(), JsError> {
+        self.renderer
+            .show_unused_pips(enabled)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_device_pip_ids(&self, offset: usize, count: usize) -> Vec<String> {
+        self.renderer.get_device_pip_ids(offset, count)
+    }
+
+    #[wasm_bindgen]
+    pub fn search_pips(&self, query: &str, limit: usize) -> Vec<String> {
+        self.renderer.search_pips(query, limit)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_decal_ids(&mut self, decal_type: ElementType) -> Result<Vec<String>, JsError> {
+        Ok(self.renderer.get_decal_ids(decal_type))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_decals(
+        &mut self,
+        decal_type: ElementType,
+        decal_ids: Vec<String>,
+    ) -> Result<js_sys::Map, JsError> {
+        let decal_info = self.renderer.get_decal_info(decal_type, &decal_ids);
+        let map = js_sys::Map::new();
+        for (id, info) in decal_info {
+            map.set(
+                &JsValue::from_str(&id),
+                &serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))?,
+            );
+        }
+        Ok(map)
+    }
+}
+
+#[wasm_bindgen]
+pub struct ViewerMachXO2 {
+    renderer: Renderer<'static, ECP5DecalID>,
+}
+
+#[wasm_bindgen]
+impl ViewerMachXO2 {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        canvas: OffscreenCanvas,
+        chipdata: &[u8],
+        colors: IColorConfig,
+        cell_colors: ICellColorConfig,
+    ) -> Result<Self, JsError> {
+        debug_log("ViewerMachXO2::new: start".to_string());
+        let colors_conf: ColorConfig = match serde_wasm_bindgen::from_value(colors.obj) {
+            Ok(colors_conf) => colors_conf,
+            Err(e) => return Err(JsError::from(e)),
+        };
+        let cell_colors_conf: CellColorConfig =
+            match serde_wasm_bindgen::from_value(cell_colors.obj) {
+                Ok(cell_colors_conf) => cell_colors_conf,
+                Err(e) => return Err(JsError::from(e)),
+            };
+
+        let db = match chipdb::decode_min_chipinfo(chipdata) {
+            Ok(db) => db,
+            Err(e) => return Err(JsError::from(&*e)),
+        };
+
+        let arch = MachXO2Arch::new(db);
+
+        let renderer = match Renderer::new(canvas, arch, colors_conf, cell_colors_conf) {
+            Ok(r) => r,
+            Err(e) => return Err(JsError::from(&*e)),
+        };
+
+        debug_log("ViewerMachXO2::new: renderer ready".to_string());
+
+        Ok(Self { renderer })
+    }
+
+    #[wasm_bindgen]
+    pub fn render(&mut self) -> Result<(), JsError> {
+        debug_log("ViewerMachXO2::render".to_string());
+        self.renderer.render().map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_json(
+        &mut self,
+        obj: INextpnrJSON,
+        report: Option<IReportJSON>,
+        do_render: Option<bool>,
+    ) -> Result<(), JsError> {
+        debug_log("ViewerMachXO2::show_json".to_string());
+        let pnr_info = PnrInfo::from_jsobj(Chip::MachXO2, obj, report)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        self.renderer
+            .show_json(pnr_info, do_render.unwrap_or(true))
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn zoom(&mut self, amt: f32, x: f32, y: f32) -> Result<(), JsError> {
+        self.renderer
+            .zoom(amt, x, y)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn pan(&mut self, x: f32, y: f32) -> Result<(), JsError> {
+        self.renderer.pan(x, y).map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_at_coords(
+        &mut self,
+        x: f32,
+        y: f32,
+        only_highlight: bool,
+    ) -> Result<JsValue, JsError> {
+        let selection = self
+            .renderer
+            .select_decal_at_canvas(x, y, only_highlight)
+            .map_err(|e| JsError::from(&*e))?;
+
+        Ok(selection
+            .map(|(et, s)| {
+                let arr = js_sys::Array::new();
+                arr.push(&JsValue::from_f64(et as u8 as f64));
+                arr.push(&JsValue::from_str(&s));
+                arr.into()
+            })
+            .unwrap_or(JsValue::NULL))
+    }
+
+    #[wasm_bindgen]
+    pub fn select(&mut self, element_type: ElementType, decal_id: &str) -> Result<(), JsError> {
+        self.renderer
+            .select_decal(element_type, decal_id, true, false)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_cone(
+        &mut self,
+        element_type: ElementType,
+        decal_id: &str,
+        direction: ConeDirection,
+        levels: u32,
+        stop_at_ff: bool,
+    ) -> Result<JsValue, JsError> {
+        let cone = self
+            .renderer
+            .highlight_cone(element_type, decal_id, direction, levels, stop_at_ff)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&cone).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_highlight(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_highlight()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_net(&self, wire: &str) -> Option<String> {
+        self.renderer.get_wire_net(wire)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_bel_pins(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_wire_bel_pins(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_bel_pins(&mut self, bel: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_bel_pins(bel)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .get_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_wire_pips(&mut self, wire: &str) -> Result<JsValue, JsError> {
+        let pips = self
+            .renderer
+            .show_wire_pips(wire)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pips).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route(
+        &mut self,
+        from_wire: &str,
+        to_wire: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route(from_wire, to_wire, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn find_shortest_route_to_pin(
+        &mut self,
+        from_wire: &str,
+        bel: &str,
+        pin: &str,
+        cost: RouteCost,
+        speed: Option<String>,
+    ) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .find_shortest_route_to_pin(from_wire, bel, pin, cost, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_route(&self, net: String) -> Result<JsValue, JsError> {
+        let route = self
+            .renderer
+            .get_route_info(&net)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&route).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_route(
+        &mut self,
+        net: String,
+        sink_wire: Option<String>,
+    ) -> Result<(), JsError> {
+        self.renderer
+            .highlight_route(&net, sink_wire.as_deref())
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn start_route_playback(&mut self, net: String) -> Result<u32, JsError> {
+        self.renderer
+            .start_route_playback(&net)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn set_route_playback_step(&mut self, step: u32) -> Result<(), JsError> {
+        self.renderer
+            .set_route_playback_step(step)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn path_step(&mut self, step: PathStep) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .path_step(step)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn select_path_segment(&mut self, path: usize, segment: usize) -> Result<JsValue, JsError> {
+        let info = self
+            .renderer
+            .select_path_segment(path, segment)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&info).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_detours(&self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .get_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn highlight_detours(&mut self, count: usize) -> Result<JsValue, JsError> {
+        let detours = self
+            .renderer
+            .highlight_detours(count)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&detours).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_congestion(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_congestion()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_utilization(
+        &mut self,
+        bel_type: Option<String>,
+        bin_size: Option<u32>,
+    ) -> Result<JsValue, JsError> {
+        let summary = self
+            .renderer
+            .show_utilization(bel_type.as_deref(), bin_size.unwrap_or(1))
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&summary).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn clear_overlay(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .clear_overlay()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_network(&mut self) -> Result<JsValue, JsError> {
+        let usage = self
+            .renderer
+            .show_clock_network()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&usage).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_domains(&mut self) -> Result<JsValue, JsError> {
+        let domains = self
+            .renderer
+            .show_clock_domains()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&domains).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_clock_crossings(&self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .get_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_clock_crossings(&mut self) -> Result<JsValue, JsError> {
+        let crossings = self
+            .renderer
+            .show_clock_crossings()
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&crossings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_speed_grades(&self) -> Vec<String> {
+        self.renderer.get_speed_grades()
+    }
+
+    #[wasm_bindgen]
+    pub fn show_pip_delays(&mut self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delays = self
+            .renderer
+            .show_pip_delays(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delays).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_net_delay(&self, net: String, speed: Option<String>) -> Result<JsValue, JsError> {
+        let delay = self
+            .renderer
+            .get_net_delay(&net, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&delay).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_paths(&self, count: usize, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_paths(count, speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn estimate_critical_paths(&self, speed: Option<String>) -> Result<JsValue, JsError> {
+        let paths = self
+            .renderer
+            .estimate_critical_paths(speed.as_deref())
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&paths).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_tile_names(&self, x: i32, y: i32) -> Vec<String> {
+        self.renderer.get_tile_names(x, y)
+    }
+
+    #[wasm_bindgen]
+    pub fn get_packages(&self) -> Vec<String> {
+        self.renderer.get_packages()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_package_pins(&self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .get_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_bel_package_pin(&self, package: &str, bel: &str) -> Result<JsValue, JsError> {
+        let pin = self.renderer.get_bel_package_pin(package, bel);
+        serde_wasm_bindgen::to_value(&pin).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_pins(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_pins(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_package_view(&mut self, package: &str) -> Result<JsValue, JsError> {
+        let pins = self
+            .renderer
+            .show_package_view(package)
+            .map_err(|e| JsError::from(&*e))?;
+        serde_wasm_bindgen::to_value(&pins).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_die_view(&mut self) -> Result<(), JsError> {
+        self.renderer
+            .show_die_view()
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn show_unused_pips(&mut self, enabled: bool) -> Result<(), JsError> {
+        self.renderer
+            .show_unused_pips(enabled)
+            .map_err(|e| JsError::from(&*e))
+    }
+
+    #[wasm_bindgen]
+    pub fn get_device_pip_ids(&self, offset: usize, count: usize) -> Vec<String> {
+        self.renderer.get_device_pip_ids(offset, count)
+    }
+
+    #[wasm_bindgen]
+    pub fn search_pips(&self, query: &str, limit: usize) -> Vec<String> {
+        self.renderer.search_pips(query, limit)
+    }
+
     #[wasm_bindgen]
     pub fn get_decal_ids(&mut self, decal_type: ElementType) -> Result<Vec<String>, JsError> {
         Ok(self.renderer.get_decal_ids(decal_type))
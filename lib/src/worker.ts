@@ -1,4 +1,29 @@
-import type {CellColorConfig, ColorConfig, ElementType, NextpnrJson, ReportJson} from '../pkg/nextpnr_renderer';
+import type {
+    BelPinInfo,
+    CandidateRoute,
+    CellColorConfig,
+    ClockCrossing,
+    ClockDomain,
+    ClockNetworkUsage,
+    ColorConfig,
+    Cone,
+    ConeDirection,
+    ElementType,
+    EstimatedPath,
+    NetDelay,
+    NetDetour,
+    NextpnrJson,
+    PackagePinInfo,
+    PathSegmentInfo,
+    PathStep,
+    PipDelay,
+    RegionUsage,
+    ReportJson,
+    RouteCost,
+    RouteInfo,
+    UtilizationSummary,
+    WirePips
+} from '../pkg/nextpnr_renderer';
 
 import {SupportedFamily, VIEWERS} from './types';
 
@@ -208,6 +233,183 @@ export class WorkerViewerAdapter {
         return this._rpc('select_at_coords', [x, y, onlyHighlight]);
     }
 
+    async highlight_cone(
+        elementType: ElementType,
+        decalId: string,
+        direction: ConeDirection,
+        levels: number,
+        stopAtFf: boolean
+    ): Promise<Cone> {
+        return this._rpc('highlight_cone', [elementType, decalId, direction, levels, stopAtFf]);
+    }
+
+    async clear_highlight(): Promise<void> {
+        await this._rpc('clear_highlight');
+    }
+
+    async get_wire_net(wire: string): Promise<string | undefined> {
+        return this._rpc('get_wire_net', [wire]);
+    }
+
+    async get_bel_pins(bel: string): Promise<BelPinInfo[]> {
+        return this._rpc('get_bel_pins', [bel]);
+    }
+
+    async get_wire_bel_pins(wire: string): Promise<BelPinInfo[]> {
+        return this._rpc('get_wire_bel_pins', [wire]);
+    }
+
+    async show_bel_pins(bel: string): Promise<BelPinInfo[]> {
+        return this._rpc('show_bel_pins', [bel]);
+    }
+
+    async get_wire_pips(wire: string): Promise<WirePips> {
+        return this._rpc('get_wire_pips', [wire]);
+    }
+
+    async show_wire_pips(wire: string): Promise<WirePips> {
+        return this._rpc('show_wire_pips', [wire]);
+    }
+
+    async find_shortest_route(
+        fromWire: string,
+        toWire: string,
+        cost: RouteCost,
+        speed?: string
+    ): Promise<CandidateRoute> {
+        return this._rpc('find_shortest_route', [fromWire, toWire, cost, speed]);
+    }
+
+    async find_shortest_route_to_pin(
+        fromWire: string,
+        bel: string,
+        pin: string,
+        cost: RouteCost,
+        speed?: string
+    ): Promise<CandidateRoute> {
+        return this._rpc('find_shortest_route_to_pin', [fromWire, bel, pin, cost, speed]);
+    }
+
+    async get_route(net: string): Promise<RouteInfo> {
+        return this._rpc('get_route', [net]);
+    }
+
+    async highlight_route(net: string, sinkWire?: string): Promise<void> {
+        await this._rpc('highlight_route', [net, sinkWire]);
+    }
+
+    async start_route_playback(net: string): Promise<number> {
+        return this._rpc('start_route_playback', [net]);
+    }
+
+    async set_route_playback_step(step: number): Promise<void> {
+        await this._rpc('set_route_playback_step', [step]);
+    }
+
+    async path_step(step: PathStep): Promise<PathSegmentInfo> {
+        return this._rpc('path_step', [step]);
+    }
+
+    async select_path_segment(path: number, segment: number): Promise<PathSegmentInfo> {
+        return this._rpc('select_path_segment', [path, segment]);
+    }
+
+    async get_detours(count: number): Promise<NetDetour[]> {
+        return this._rpc('get_detours', [count]);
+    }
+
+    async highlight_detours(count: number): Promise<NetDetour[]> {
+        return this._rpc('highlight_detours', [count]);
+    }
+
+    async show_congestion(): Promise<RegionUsage[]> {
+        return this._rpc('show_congestion');
+    }
+
+    async show_utilization(belType?: string, binSize?: number): Promise<UtilizationSummary> {
+        return this._rpc('show_utilization', [belType, binSize]);
+    }
+
+    async show_clock_network(): Promise<ClockNetworkUsage> {
+        return this._rpc('show_clock_network');
+    }
+
+    async clear_overlay(): Promise<void> {
+        await this._rpc('clear_overlay');
+    }
+
+    async show_clock_domains(): Promise<ClockDomain[]> {
+        return this._rpc('show_clock_domains');
+    }
+
+    async get_clock_crossings(): Promise<ClockCrossing[]> {
+        return this._rpc('get_clock_crossings');
+    }
+
+    async show_clock_crossings(): Promise<ClockCrossing[]> {
+        return this._rpc('show_clock_crossings');
+    }
+
+    async get_speed_grades(): Promise<string[]> {
+        return this._rpc('get_speed_grades');
+    }
+
+    async show_pip_delays(speed?: string): Promise<PipDelay[]> {
+        return this._rpc('show_pip_delays', [speed]);
+    }
+
+    async get_net_delay(net: string, speed?: string): Promise<NetDelay> {
+        return this._rpc('get_net_delay', [net, speed]);
+    }
+
+    async estimate_paths(count: number, speed?: string): Promise<EstimatedPath[]> {
+        return this._rpc('estimate_paths', [count, speed]);
+    }
+
+    async estimate_critical_paths(speed?: string): Promise<EstimatedPath[]> {
+        return this._rpc('estimate_critical_paths', [speed]);
+    }
+
+    async get_tile_names(x: number, y: number): Promise<string[]> {
+        return this._rpc('get_tile_names', [x, y]);
+    }
+
+    async get_packages(): Promise<string[]> {
+        return this._rpc('get_packages');
+    }
+
+    async get_package_pins(pkg: string): Promise<PackagePinInfo[]> {
+        return this._rpc('get_package_pins', [pkg]);
+    }
+
+    async get_bel_package_pin(pkg: string, bel: string): Promise<PackagePinInfo | undefined> {
+        return this._rpc('get_bel_package_pin', [pkg, bel]);
+    }
+
+    async show_package_pins(pkg: string): Promise<PackagePinInfo[]> {
+        return this._rpc('show_package_pins', [pkg]);
+    }
+
+    async show_package_view(pkg: string): Promise<PackagePinInfo[]> {
+        return this._rpc('show_package_view', [pkg]);
+    }
+
+    async show_die_view(): Promise<void> {
+        await this._rpc('show_die_view');
+    }
+
+    async show_unused_pips(enabled: boolean): Promise<void> {
+        await this._rpc('show_unused_pips', [enabled]);
+    }
+
+    async get_device_pip_ids(offset: number, count: number): Promise<string[]> {
+        return this._rpc('get_device_pip_ids', [offset, count]);
+    }
+
+    async search_pips(query: string, limit: number): Promise<string[]> {
+        return this._rpc('search_pips', [query, limit]);
+    }
+
     async get_decal_ids(decalType: ElementType): Promise<string[]> {
         return this._rpc('get_decal_ids', [decalType]);
     }